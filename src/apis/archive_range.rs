@@ -0,0 +1,366 @@
+use std::ops::RangeInclusive;
+#[cfg(feature = "async")]
+use std::{
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+#[cfg(feature = "async")]
+use futures_core::Stream;
+
+use super::date::ArchiveDate;
+use super::dedup::{DedupKey, Deduplicator, LruStore};
+use super::news_archive::GetNewsArchiveParams;
+#[cfg(feature = "async")]
+use super::pagination::{AsyncPages, PageFuture};
+use super::pagination::{Pages, Paginated};
+use crate::{ApiResult, Article, Error, NewsResponse};
+
+/// Default number of article ids remembered by an archive range to drop the duplicates.
+pub const DEFAULT_RANGE_SEEN_CAPACITY: usize = 10_000;
+
+/// Size of the windows a date range of the archive endpoint is split into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Window {
+    Days(u32),
+    Weeks(u32),
+}
+
+impl Window {
+    /// Length of the window in days.
+    pub fn days(&self) -> i64 {
+        match *self {
+            Window::Days(days) => i64::from(days),
+            Window::Weeks(weeks) => i64::from(weeks) * 7,
+        }
+    }
+}
+
+/// Progress of an archive range, reported after each fetched page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeProgress {
+    /// Index of the current window, starting from 1.
+    pub window: usize,
+    /// Number of windows of the range.
+    pub windows: usize,
+    /// Start of the current window.
+    pub from_date: ArchiveDate,
+    /// End of the current window.
+    pub to_date: ArchiveDate,
+    /// Number of pages fetched in the current window.
+    pub pages: usize,
+    /// Number of pages fetched in all windows.
+    pub total_pages: usize,
+    /// Number of unique articles returned so far.
+    pub articles: usize,
+    /// Number of duplicate articles dropped so far.
+    pub duplicates: usize,
+}
+
+/// Splits `range` into windows of `window` days, as `(from_date, to_date)` pairs.
+///
+/// The time of the start and end of the range, if any, is kept on the first and last windows,
+/// e.g. a range starting at `2024-01-01 10:00:00` split by day starts with `2024-01-01 10:00:00..=2024-01-01`.
+fn split(
+    range: RangeInclusive<ArchiveDate>,
+    window: Window,
+) -> ApiResult<Vec<(ArchiveDate, ArchiveDate)>> {
+    let (start, end) = range.into_inner();
+    if window.days() < 1 {
+        return Err(Error::invalid_parameter(
+            "window",
+            "must be at least 1 day".to_string(),
+        ));
+    }
+    if start.is_after(&end) {
+        return Err(Error::invalid_parameter(
+            "from_date",
+            format!("must not be after to_date, got {start} > {end}"),
+        ));
+    }
+    let mut windows = Vec::new();
+    let mut from = start;
+    loop {
+        let to = from.date().add_days(window.days() - 1);
+        if to >= end.date() {
+            windows.push((from, end));
+            return Ok(windows);
+        }
+        windows.push((from, to));
+        from = to.add_days(1);
+    }
+}
+
+/// Callback of `on_progress`.
+type ProgressCallback<'a> = Box<dyn FnMut(&RangeProgress) + Send + 'a>;
+
+/// Range progress shared by the blocking and async archive ranges.
+struct RangeState<'a> {
+    params: GetNewsArchiveParams,
+    windows: std::vec::IntoIter<(ArchiveDate, ArchiveDate)>,
+    error: Option<Error>,
+    max_pages: Option<usize>,
+    seen: Deduplicator<LruStore>,
+    progress: Option<RangeProgress>,
+    windows_count: usize,
+    total_pages: usize,
+    articles: usize,
+    duplicates: usize,
+    on_progress: Option<ProgressCallback<'a>>,
+}
+
+impl<'a> RangeState<'a> {
+    fn new(
+        params: &GetNewsArchiveParams,
+        range: RangeInclusive<ArchiveDate>,
+        window: Window,
+    ) -> Self {
+        let (windows, error) = match split(range, window) {
+            Ok(windows) => (windows, None),
+            Err(e) => (Vec::new(), Some(e)),
+        };
+        Self {
+            params: params.clone(),
+            windows_count: windows.len(),
+            windows: windows.into_iter(),
+            error,
+            max_pages: None,
+            seen: Self::deduplicator(DEFAULT_RANGE_SEEN_CAPACITY),
+            progress: None,
+            total_pages: 0,
+            articles: 0,
+            duplicates: 0,
+            on_progress: None,
+        }
+    }
+
+    /// Deduplicator remembering the `capacity` most recent article ids.
+    fn deduplicator(capacity: usize) -> Deduplicator<LruStore> {
+        Deduplicator::bounded(capacity).keys([DedupKey::ArticleId])
+    }
+
+    /// Returns the params of the first page of the next window, if any.
+    fn next_window(&mut self) -> Option<GetNewsArchiveParams> {
+        let (from_date, to_date) = self.windows.next()?;
+        let window = self
+            .progress
+            .as_ref()
+            .map_or(1, |progress| progress.window + 1);
+        self.progress = Some(RangeProgress {
+            window,
+            windows: self.windows_count,
+            from_date,
+            to_date,
+            pages: 0,
+            total_pages: self.total_pages,
+            articles: self.articles,
+            duplicates: self.duplicates,
+        });
+        let mut params = self.params.clone();
+        params.from_date = Some(from_date);
+        params.to_date = Some(to_date);
+        params.set_page(None);
+        Some(params)
+    }
+
+    /// Drops the already returned articles of a fetched page and reports the progress.
+    fn process(&mut self, page: NewsResponse) -> Vec<Article> {
+        let mut articles = page.results;
+        let before = articles.len();
+        articles.retain(|article| self.seen.is_new(article));
+        self.total_pages += 1;
+        self.articles += articles.len();
+        self.duplicates += before - articles.len();
+        if let Some(progress) = self.progress.as_mut() {
+            progress.pages += 1;
+            progress.total_pages = self.total_pages;
+            progress.articles = self.articles;
+            progress.duplicates = self.duplicates;
+            if let Some(on_progress) = self.on_progress.as_mut() {
+                on_progress(progress);
+            }
+        }
+        articles
+    }
+
+    /// Stops the range, e.g. after an error.
+    fn stop(&mut self) {
+        self.windows = Vec::new().into_iter();
+    }
+}
+
+/// Iterator over the articles of the archive endpoint for a date range, see `NewsArchive::get_news_archive_range`.
+///
+/// The range is split into windows, each one paginated until `nextPage` is null,
+/// and the articles already returned by a previous window or page are dropped,
+/// remembering the last `seen_capacity` article ids.\
+/// The iteration stops after the first error.
+pub struct ArchiveRange<'a, C> {
+    client: &'a C,
+    fetch: fn(&C, &GetNewsArchiveParams) -> ApiResult<NewsResponse>,
+    state: RangeState<'a>,
+    pages: Option<Pages<'a, C, GetNewsArchiveParams>>,
+    current: std::vec::IntoIter<Article>,
+}
+
+impl<'a, C> ArchiveRange<'a, C> {
+    pub(crate) fn new(
+        client: &'a C,
+        params: &GetNewsArchiveParams,
+        range: RangeInclusive<ArchiveDate>,
+        window: Window,
+        fetch: fn(&C, &GetNewsArchiveParams) -> ApiResult<NewsResponse>,
+    ) -> Self {
+        Self {
+            client,
+            fetch,
+            state: RangeState::new(params, range, window),
+            pages: None,
+            current: Vec::new().into_iter(),
+        }
+    }
+
+    /// Fetches at most `max_pages` pages per window.
+    pub fn max_pages_per_window(mut self, max_pages: usize) -> Self {
+        self.state.max_pages = Some(max_pages);
+        self
+    }
+
+    /// Calls `on_progress` after each fetched page.
+    pub fn on_progress(mut self, on_progress: impl FnMut(&RangeProgress) + Send + 'a) -> Self {
+        self.state.on_progress = Some(Box::new(on_progress));
+        self
+    }
+
+    /// Sets the number of article ids remembered to drop the duplicates, 10,000 by default.\
+    /// The duplicates come from windows or pages overlapping, so recent ids are enough.
+    pub fn seen_capacity(mut self, capacity: usize) -> Self {
+        self.state.seen = RangeState::deduplicator(capacity);
+        self
+    }
+}
+
+impl<C> Iterator for ArchiveRange<'_, C> {
+    type Item = ApiResult<Article>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(e) = self.state.error.take() {
+            return Some(Err(e));
+        }
+        loop {
+            if let Some(article) = self.current.next() {
+                return Some(Ok(article));
+            }
+            let page = match self.pages.as_mut().and_then(Iterator::next) {
+                Some(page) => page,
+                None => {
+                    let params = self.state.next_window()?;
+                    let pages = Pages::new(self.client, params, self.fetch);
+                    self.pages = Some(match self.state.max_pages {
+                        Some(max_pages) => pages.max_pages(max_pages),
+                        None => pages,
+                    });
+                    continue;
+                }
+            };
+            match page {
+                Ok(page) => self.current = self.state.process(page).into_iter(),
+                Err(e) => {
+                    self.state.stop();
+                    self.pages = None;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+/// Async counterpart of `ArchiveRange`, implementing `Stream`.\
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub struct AsyncArchiveRange<'a> {
+    fetch: Arc<dyn Fn(GetNewsArchiveParams) -> PageFuture<'a> + Send + Sync + 'a>,
+    state: RangeState<'a>,
+    pages: Option<AsyncPages<'a, GetNewsArchiveParams>>,
+    current: std::vec::IntoIter<Article>,
+}
+
+#[cfg(feature = "async")]
+impl<'a> AsyncArchiveRange<'a> {
+    pub(crate) fn new(
+        params: &GetNewsArchiveParams,
+        range: RangeInclusive<ArchiveDate>,
+        window: Window,
+        fetch: impl Fn(GetNewsArchiveParams) -> PageFuture<'a> + Send + Sync + 'a,
+    ) -> Self {
+        Self {
+            fetch: Arc::new(fetch),
+            state: RangeState::new(params, range, window),
+            pages: None,
+            current: Vec::new().into_iter(),
+        }
+    }
+
+    /// Fetches at most `max_pages` pages per window.
+    pub fn max_pages_per_window(mut self, max_pages: usize) -> Self {
+        self.state.max_pages = Some(max_pages);
+        self
+    }
+
+    /// Calls `on_progress` after each fetched page.
+    pub fn on_progress(mut self, on_progress: impl FnMut(&RangeProgress) + Send + 'a) -> Self {
+        self.state.on_progress = Some(Box::new(on_progress));
+        self
+    }
+
+    /// Sets the number of article ids remembered to drop the duplicates, 10,000 by default.\
+    /// The duplicates come from windows or pages overlapping, so recent ids are enough.
+    pub fn seen_capacity(mut self, capacity: usize) -> Self {
+        self.state.seen = RangeState::deduplicator(capacity);
+        self
+    }
+}
+
+#[cfg(feature = "async")]
+impl Stream for AsyncArchiveRange<'_> {
+    type Item = ApiResult<Article>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if let Some(e) = this.state.error.take() {
+            return Poll::Ready(Some(Err(e)));
+        }
+        loop {
+            if let Some(article) = this.current.next() {
+                return Poll::Ready(Some(Ok(article)));
+            }
+            let page = match this.pages.as_mut() {
+                Some(pages) => match Pin::new(pages).poll_next(cx) {
+                    Poll::Ready(page) => page,
+                    Poll::Pending => return Poll::Pending,
+                },
+                None => None,
+            };
+            match page {
+                Some(Ok(page)) => this.current = this.state.process(page).into_iter(),
+                Some(Err(e)) => {
+                    this.state.stop();
+                    this.pages = None;
+                    return Poll::Ready(Some(Err(e)));
+                }
+                None => {
+                    let Some(params) = this.state.next_window() else {
+                        return Poll::Ready(None);
+                    };
+                    let fetch = this.fetch.clone();
+                    let pages = AsyncPages::new(params, move |params| fetch(params));
+                    this.pages = Some(match this.state.max_pages {
+                        Some(max_pages) => pages.max_pages(max_pages),
+                        None => pages,
+                    });
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use super::validation::Validator;
+use crate::{ApiResult, Error};
+
+/// Values accepted by the list setters of the params builders: an iterator of values,
+/// e.g. strings parsed into a typed enum, or a single value of the typed enums.
+pub trait IntoValues<T> {
+    /// Collects the values.
+    ///
+    /// # Returns
+    ///
+    /// An `Error::InvalidParameter` listing every value which fails to convert, if any.
+    fn into_values(self) -> ApiResult<Vec<T>>;
+}
+
+impl<T, I> IntoValues<T> for I
+where
+    I: IntoIterator,
+    I::Item: TryInto<T>,
+    <I::Item as TryInto<T>>::Error: Into<Error>,
+{
+    fn into_values(self) -> ApiResult<Vec<T>> {
+        let mut validator = Validator::new();
+        let mut values = Vec::new();
+        for value in self {
+            match value.try_into() {
+                Ok(value) => values.push(value),
+                Err(e) => {
+                    validator.merge(Err(e.into()));
+                }
+            }
+        }
+        validator.finish().map(|()| values)
+    }
+}
+
+/// Implements `IntoValues` for a single value of a typed enum.
+macro_rules! single_value {
+    ($($ty:ty),*) => {
+        $(
+            impl IntoValues<$ty> for $ty {
+                fn into_values(self) -> ApiResult<Vec<$ty>> {
+                    Ok(vec![self])
+                }
+            }
+        )*
+    };
+}
+
+single_value!(
+    super::Category,
+    super::Country,
+    super::Language,
+    super::PriorityDomain,
+    super::Sentiment
+);
+
+/// Declares the builder of a params struct, with one setter per field.
+///
+/// Fields are declared as `kind name` or `kind name: Type`, where kind is one of:
+/// * `string`: `Option<String>`, set from `impl Into<String>`
+/// * `joined`: `Option<String>` holding a comma separated list, set from `impl IntoValues<String>`
+/// * `values`: `Option<Vec<Type>>`, set from `impl IntoValues<Type>`, e.g. strings; a failed conversion is reported by `build`
+/// * `value`: `Option<Type>`, set from `impl Into<Type>`
+/// * `parsed`: `Option<Type>`, set from `impl TryInto<Type>`, e.g. a string; a failed conversion is reported by `build`
+macro_rules! params_builder {
+    (
+        $(#[$meta:meta])*
+        $builder:ident => $params:ident {
+            $($kind:ident $field:ident $(: $ty:ty)?,)*
+        }
+    ) => {
+        impl $params {
+            #[doc = concat!("Returns a builder of `", stringify!($params), "`, validating the parameters in `build`.")]
+            pub fn builder() -> $builder {
+                $builder::default()
+            }
+        }
+
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default)]
+        pub struct $builder {
+            params: $params,
+            /// Violations found by the `parsed` setters.
+            violations: Vec<$crate::Violation>,
+        }
+
+        impl $builder {
+            $(
+                $crate::apis::builder::params_builder!(@setter $params $kind $field $(: $ty)?);
+            )*
+
+            /// Validates and returns the parameters.
+            ///
+            /// # Returns
+            ///
+            /// An `Error::InvalidParameter` listing all violations, if any.
+            pub fn build(self) -> $crate::ApiResult<$params> {
+                $crate::apis::validation::Validator::new()
+                    .extend(self.violations)
+                    .merge($crate::apis::Validate::validate(&self.params))
+                    .finish()?;
+                Ok(self.params)
+            }
+        }
+    };
+    (@setter $params:ident string $field:ident) => {
+        #[doc = concat!("Sets `", stringify!($field), "`, see `", stringify!($params), "::", stringify!($field), "`.")]
+        pub fn $field(mut self, $field: impl Into<String>) -> Self {
+            self.params.$field = Some($field.into());
+            self
+        }
+    };
+    (@setter $params:ident joined $field:ident) => {
+        #[doc = concat!("Sets `", stringify!($field), "`, see `", stringify!($params), "::", stringify!($field), "`.")]
+        pub fn $field(mut self, $field: impl $crate::apis::IntoValues<String>) -> Self {
+            match $field.into_values() {
+                Ok(values) => self.params.$field = Some(values.join(",")),
+                Err(e) => self.violations.extend($crate::apis::validation::violations_of(
+                    stringify!($field),
+                    e,
+                )),
+            }
+            self
+        }
+    };
+    (@setter $params:ident values $field:ident: $ty:ty) => {
+        #[doc = concat!("Sets `", stringify!($field), "`, see `", stringify!($params), "::", stringify!($field), "`.\\")]
+        #[doc = "Values which fail to convert are reported by `build`."]
+        pub fn $field(mut self, $field: impl $crate::apis::IntoValues<$ty>) -> Self {
+            match $field.into_values() {
+                Ok(values) => self.params.$field = Some(values),
+                Err(e) => self.violations.extend($crate::apis::validation::violations_of(
+                    stringify!($field),
+                    e,
+                )),
+            }
+            self
+        }
+    };
+    (@setter $params:ident value $field:ident: $ty:ty) => {
+        #[doc = concat!("Sets `", stringify!($field), "`, see `", stringify!($params), "::", stringify!($field), "`.")]
+        pub fn $field(mut self, $field: impl Into<$ty>) -> Self {
+            self.params.$field = Some($field.into());
+            self
+        }
+    };
+    (@setter $params:ident parsed $field:ident: $ty:ty) => {
+        #[doc = concat!("Sets `", stringify!($field), "`, see `", stringify!($params), "::", stringify!($field), "`.\\")]
+        #[doc = "A value which fails to convert is reported by `build`."]
+        pub fn $field<T>(mut self, $field: T) -> Self
+        where
+            T: TryInto<$ty>,
+            T::Error: Into<$crate::Error>,
+        {
+            match $field.try_into() {
+                Ok(value) => self.params.$field = Some(value),
+                Err(e) => self.violations.extend($crate::apis::validation::violations_of(
+                    stringify!($field),
+                    e.into(),
+                )),
+            }
+            self
+        }
+    };
+}
+
+pub(crate) use params_builder;
@@ -0,0 +1,412 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Serialize, Serializer};
+
+use crate::{ApiResult, Error};
+
+/// Max number of hours of a `Timeframe`.
+pub const MAX_TIMEFRAME_HOURS: u8 = 48;
+/// Max number of minutes of a `Timeframe`.
+pub const MAX_TIMEFRAME_MINUTES: u16 = 48 * 60;
+
+/// Date, with an optional time, of the `from_date` and `to_date` parameters of the archive endpoint.
+///
+/// Sent as `YYYY-MM-DD` or `YYYY-MM-DD HH:MM:SS`.\
+/// Parse it from a string, or convert it from `chrono` or `time` values with the features of the same name.\
+/// Ordered by date then time, a date without a time first; use `is_after` to check a range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ArchiveDate {
+    year: i32,
+    month: u8,
+    day: u8,
+    time: Option<(u8, u8, u8)>,
+}
+
+impl ArchiveDate {
+    /// Creates a date, failing if it does not exist.
+    pub fn from_ymd(year: i32, month: u8, day: u8) -> ApiResult<Self> {
+        if !(1..=9999).contains(&year) || !(1..=12).contains(&month) {
+            return Err(invalid_date(format!("{year:04}-{month:02}-{day:02}")));
+        }
+        if day == 0 || day > days_in_month(year, month) {
+            return Err(invalid_date(format!("{year:04}-{month:02}-{day:02}")));
+        }
+        Ok(Self {
+            year,
+            month,
+            day,
+            time: None,
+        })
+    }
+
+    /// Adds a time to the date, failing if it does not exist.
+    pub fn and_hms(self, hour: u8, minute: u8, second: u8) -> ApiResult<Self> {
+        if hour > 23 || minute > 59 || second > 59 {
+            return Err(invalid_date(format!(
+                "{self} {hour:02}:{minute:02}:{second:02}"
+            )));
+        }
+        Ok(Self {
+            time: Some((hour, minute, second)),
+            ..self
+        })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// `(hour, minute, second)`, if any.
+    pub fn time(&self) -> Option<(u8, u8, u8)> {
+        self.time
+    }
+
+    /// The date without its time.
+    pub fn date(self) -> Self {
+        Self { time: None, ..self }
+    }
+
+    /// Whether `self` is after `other` as bounds of a range.
+    ///
+    /// The API reads a date without a time as the whole day, so calendar dates are compared
+    /// when either has no time, e.g. `2024-01-01 10:00:00` is not after `2024-01-01`.
+    pub fn is_after(&self, other: &Self) -> bool {
+        match (self.time, other.time) {
+            (Some(_), Some(_)) => self > other,
+            _ => self.date() > other.date(),
+        }
+    }
+
+    /// The date `days` days later, or earlier if negative, without its time.
+    pub(crate) fn add_days(self, days: i64) -> Self {
+        let (year, month, day) =
+            civil_from_days(days_from_civil(self.year, self.month, self.day) + days);
+        Self {
+            year,
+            month,
+            day,
+            time: None,
+        }
+    }
+}
+
+/// Number of days since 1970-01-01, see <http://howardhinnant.github.io/date_algorithms.html>.
+pub(crate) fn days_from_civil(year: i32, month: u8, day: u8) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Inverse of `days_from_civil`.
+fn civil_from_days(days: i64) -> (i32, u8, u8) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u8;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u8;
+    let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+    (year, month, day)
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn invalid_date(value: String) -> Error {
+    Error::invalid_parameter(
+        "date",
+        format!("invalid date `{value}`, expected YYYY-MM-DD or YYYY-MM-DD HH:MM:SS"),
+    )
+}
+
+impl Ord for ArchiveDate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.year, self.month, self.day)
+            .cmp(&(other.year, other.month, other.day))
+            .then_with(|| match (self.time, other.time) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Less,
+                (Some(_), None) => Ordering::Greater,
+                (Some(time), Some(other)) => time.cmp(&other),
+            })
+    }
+}
+
+impl PartialOrd for ArchiveDate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for ArchiveDate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+        let (date, time) = match value.split_once([' ', 'T']) {
+            Some((date, time)) => (date, Some(time)),
+            None => (value, None),
+        };
+        let number = |part: Option<&str>, len: usize| {
+            part.filter(|part| part.len() == len && part.bytes().all(|b| b.is_ascii_digit()))
+                .and_then(|part| part.parse::<i32>().ok())
+                .ok_or_else(|| invalid_date(value.to_string()))
+        };
+        let mut parts = date.split('-');
+        let (year, month, day) = (
+            number(parts.next(), 4)?,
+            number(parts.next(), 2)?,
+            number(parts.next(), 2)?,
+        );
+        if parts.next().is_some() {
+            return Err(invalid_date(value.to_string()));
+        }
+        let date = ArchiveDate::from_ymd(year, month as u8, day as u8)
+            .map_err(|_| invalid_date(value.to_string()))?;
+        match time {
+            Some(time) => {
+                let mut parts = time.split(':');
+                let (hour, minute, second) = (
+                    number(parts.next(), 2)?,
+                    number(parts.next(), 2)?,
+                    number(parts.next(), 2)?,
+                );
+                if parts.next().is_some() {
+                    return Err(invalid_date(value.to_string()));
+                }
+                date.and_hms(hour as u8, minute as u8, second as u8)
+                    .map_err(|_| invalid_date(value.to_string()))
+            }
+            None => Ok(date),
+        }
+    }
+}
+
+impl TryFrom<&str> for ArchiveDate {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for ArchiveDate {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for ArchiveDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)?;
+        if let Some((hour, minute, second)) = self.time {
+            write!(f, " {hour:02}:{minute:02}:{second:02}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for ArchiveDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::NaiveDate> for ArchiveDate {
+    type Error = Error;
+
+    /// Fails on the years the API does not accept, out of 1 to 9999.
+    fn try_from(date: chrono::NaiveDate) -> Result<Self, Self::Error> {
+        use chrono::Datelike;
+        Self::from_ymd(date.year(), date.month() as u8, date.day() as u8)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<chrono::NaiveDateTime> for ArchiveDate {
+    type Error = Error;
+
+    fn try_from(datetime: chrono::NaiveDateTime) -> Result<Self, Self::Error> {
+        use chrono::Timelike;
+        Self::try_from(datetime.date())?.and_hms(
+            datetime.hour() as u8,
+            datetime.minute() as u8,
+            datetime.second() as u8,
+        )
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> TryFrom<chrono::DateTime<Tz>> for ArchiveDate {
+    type Error = Error;
+
+    /// Converts the datetime to UTC.
+    fn try_from(datetime: chrono::DateTime<Tz>) -> Result<Self, Self::Error> {
+        Self::try_from(datetime.naive_utc())
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::Date> for ArchiveDate {
+    type Error = Error;
+
+    /// Fails on the years the API does not accept, out of 1 to 9999.
+    fn try_from(date: time::Date) -> Result<Self, Self::Error> {
+        Self::from_ymd(date.year(), u8::from(date.month()), date.day())
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::PrimitiveDateTime> for ArchiveDate {
+    type Error = Error;
+
+    fn try_from(datetime: time::PrimitiveDateTime) -> Result<Self, Self::Error> {
+        Self::try_from(datetime.date())?.and_hms(
+            datetime.hour(),
+            datetime.minute(),
+            datetime.second(),
+        )
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::OffsetDateTime> for ArchiveDate {
+    type Error = Error;
+
+    /// Converts the datetime to UTC.
+    fn try_from(datetime: time::OffsetDateTime) -> Result<Self, Self::Error> {
+        let datetime = datetime.to_offset(time::UtcOffset::UTC);
+        Self::try_from(time::PrimitiveDateTime::new(
+            datetime.date(),
+            datetime.time(),
+        ))
+    }
+}
+
+/// Timeframe of the `timeframe` parameter of the latest and crypto endpoints.
+///
+/// Sent as `6` for 6 hours, or `15m` for 15 minutes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Timeframe {
+    /// 1 to 48 hours.
+    Hours(u8),
+    /// 1 to 2880 minutes.
+    Minutes(u16),
+}
+
+impl Timeframe {
+    /// Creates a timeframe of `hours` hours, failing if out of range.
+    pub fn hours(hours: u8) -> ApiResult<Self> {
+        let timeframe = Timeframe::Hours(hours);
+        timeframe.check()?;
+        Ok(timeframe)
+    }
+
+    /// Creates a timeframe of `minutes` minutes, failing if out of range.
+    pub fn minutes(minutes: u16) -> ApiResult<Self> {
+        let timeframe = Timeframe::Minutes(minutes);
+        timeframe.check()?;
+        Ok(timeframe)
+    }
+
+    /// Checks the timeframe is within the range accepted by the API.
+    pub fn check(&self) -> ApiResult<()> {
+        let valid = match *self {
+            Timeframe::Hours(hours) => (1..=MAX_TIMEFRAME_HOURS).contains(&hours),
+            Timeframe::Minutes(minutes) => (1..=MAX_TIMEFRAME_MINUTES).contains(&minutes),
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(Error::invalid_parameter(
+                "timeframe",
+                format!(
+                    "must be 1 to {MAX_TIMEFRAME_HOURS} hours or 1 to {MAX_TIMEFRAME_MINUTES} minutes, got `{self}`"
+                ),
+            ))
+        }
+    }
+}
+
+impl FromStr for Timeframe {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim();
+        let invalid = || {
+            Error::invalid_parameter(
+                "timeframe",
+                format!("invalid timeframe `{value}`, expected e.g. `6` for 6 hours or `15m` for 15 minutes"),
+            )
+        };
+        match value.strip_suffix('m') {
+            Some(minutes) => Timeframe::minutes(minutes.parse().map_err(|_| invalid())?),
+            None => Timeframe::hours(value.parse().map_err(|_| invalid())?),
+        }
+    }
+}
+
+impl TryFrom<&str> for Timeframe {
+    type Error = Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<String> for Timeframe {
+    type Error = Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Display for Timeframe {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Timeframe::Hours(hours) => write!(f, "{hours}"),
+            Timeframe::Minutes(minutes) => write!(f, "{minutes}m"),
+        }
+    }
+}
+
+impl Serialize for Timeframe {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
@@ -0,0 +1,306 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+#[cfg(feature = "async")]
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+#[cfg(feature = "async")]
+use futures_core::Stream;
+
+use crate::{ApiResult, Article};
+
+/// Query parameters of tracking links, dropped from canonical links.
+const TRACKING_PARAMS: [&str; 6] = ["fbclid", "gclid", "igshid", "mc_cid", "mc_eid", "ref"];
+
+/// What makes two articles the same for a `Deduplicator`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DedupKey {
+    /// Same `article_id`.
+    ArticleId,
+    /// Same `link`, ignoring the scheme, `www.`, trailing slash, fragment and tracking parameters.
+    Link,
+    /// Same `title`, ignoring case, punctuation and whitespace.
+    /// Not a default key: generic headlines, e.g. "Market update", are shared by unrelated articles.
+    Title,
+}
+
+impl DedupKey {
+    /// All keys.
+    pub const ALL: [DedupKey; 3] = [DedupKey::ArticleId, DedupKey::Link, DedupKey::Title];
+    /// Keys of a new `Deduplicator`.
+    pub const DEFAULT: [DedupKey; 2] = [DedupKey::ArticleId, DedupKey::Link];
+
+    /// Hash of the key of `article`, if it has one.
+    fn digest(&self, article: &Article) -> Option<u64> {
+        let value = match self {
+            DedupKey::ArticleId => Some(article.article_id.clone()),
+            DedupKey::Link => article.link.as_deref().map(canonical_link),
+            DedupKey::Title => article.title.as_deref().map(normalized_title),
+        }
+        .filter(|value| !value.is_empty())?;
+        let mut hasher = DefaultHasher::new();
+        Hash::hash(self, &mut hasher);
+        value.hash(&mut hasher);
+        Some(hasher.finish())
+    }
+}
+
+/// Drops the scheme, `www.`, the trailing slash, the fragment and the tracking parameters of `link`,
+/// and lowercases its host.
+fn canonical_link(link: &str) -> String {
+    let link = link.trim();
+    let link = link.split_once('#').map_or(link, |(link, _)| link);
+    let link = link.split_once("://").map_or(link, |(_, link)| link);
+    let (link, query) = link.split_once('?').unwrap_or((link, ""));
+    let (host, path) = link.split_once('/').unwrap_or((link, ""));
+    let host = host.to_lowercase();
+    let host = host.strip_prefix("www.").unwrap_or(&host);
+    let mut canonical = format!("{host}/{}", path.trim_end_matches('/'));
+    let query: Vec<&str> = query
+        .split('&')
+        .filter(|param| {
+            let name = param.split('=').next().unwrap_or_default().to_lowercase();
+            !name.is_empty()
+                && !name.starts_with("utm_")
+                && !TRACKING_PARAMS.contains(&name.as_str())
+        })
+        .collect();
+    if !query.is_empty() {
+        canonical.push('?');
+        canonical.push_str(&query.join("&"));
+    }
+    canonical
+}
+
+/// Lowercases `title` and keeps only its alphanumeric words, separated by a single space.
+fn normalized_title(title: &str) -> String {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Memory of the keys seen by a `Deduplicator`.
+pub trait SeenStore {
+    /// Records `key`, returning whether it was not seen before.
+    fn insert(&mut self, key: u64) -> bool;
+
+    /// Whether `key` was seen before.
+    fn contains(&self, key: u64) -> bool;
+}
+
+/// Unbounded store, keeping every key.
+impl SeenStore for HashSet<u64> {
+    fn insert(&mut self, key: u64) -> bool {
+        HashSet::insert(self, key)
+    }
+
+    fn contains(&self, key: u64) -> bool {
+        HashSet::contains(self, &key)
+    }
+}
+
+/// Bounded store, forgetting the least recently seen keys beyond its capacity.\
+/// Suited to long-running pollers.
+#[derive(Debug, Clone)]
+pub struct LruStore {
+    capacity: usize,
+    tick: u64,
+    ticks: HashMap<u64, u64>,
+    keys: BTreeMap<u64, u64>,
+}
+
+impl LruStore {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            tick: 0,
+            ticks: HashMap::new(),
+            keys: BTreeMap::new(),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.ticks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ticks.is_empty()
+    }
+}
+
+impl SeenStore for LruStore {
+    fn insert(&mut self, key: u64) -> bool {
+        self.tick += 1;
+        let seen = match self.ticks.insert(key, self.tick) {
+            Some(tick) => {
+                self.keys.remove(&tick);
+                true
+            }
+            None => false,
+        };
+        self.keys.insert(self.tick, key);
+        while self.ticks.len() > self.capacity {
+            if let Some((_, oldest)) = self.keys.pop_first() {
+                self.ticks.remove(&oldest);
+            }
+        }
+        !seen
+    }
+
+    fn contains(&self, key: u64) -> bool {
+        self.ticks.contains_key(&key)
+    }
+}
+
+/// Drops the articles already seen, e.g. the same article returned by the latest and archive endpoints
+/// or syndicated copies of it.
+///
+/// An article is a duplicate when any of its keys (`article_id` and canonical link by default, see `keys`
+/// to also match the normalized title) was seen before.
+/// Keys are kept as 64 bits hashes, in an unbounded `HashSet` or a bounded `LruStore`.
+#[derive(Debug, Clone)]
+pub struct Deduplicator<S = HashSet<u64>> {
+    keys: Vec<DedupKey>,
+    store: S,
+}
+
+impl Deduplicator {
+    /// Creates a deduplicator remembering every key.
+    pub fn new() -> Self {
+        Self::with_store(HashSet::new())
+    }
+}
+
+impl Default for Deduplicator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deduplicator<LruStore> {
+    /// Creates a deduplicator remembering the `capacity` most recently seen keys.
+    pub fn bounded(capacity: usize) -> Self {
+        Self::with_store(LruStore::new(capacity))
+    }
+}
+
+impl<S: SeenStore> Deduplicator<S> {
+    /// Creates a deduplicator keeping its keys in `store`.
+    pub fn with_store(store: S) -> Self {
+        Self {
+            keys: DedupKey::DEFAULT.to_vec(),
+            store,
+        }
+    }
+
+    /// Sets the keys used to compare articles.
+    pub fn keys(mut self, keys: impl IntoIterator<Item = DedupKey>) -> Self {
+        self.keys = keys.into_iter().collect();
+        self
+    }
+
+    /// Records the keys of `article`, returning whether none of them was seen before.
+    pub fn is_new(&mut self, article: &Article) -> bool {
+        let hashes: Vec<u64> = self
+            .keys
+            .iter()
+            .filter_map(|key| key.digest(article))
+            .collect();
+        let seen = hashes.iter().any(|hash| self.store.contains(*hash));
+        for hash in hashes {
+            self.store.insert(hash);
+        }
+        !seen
+    }
+
+    /// Keeps the new articles of `articles`, passing their errors through.
+    pub fn filter<I>(&mut self, articles: I) -> Dedup<'_, I::IntoIter, S>
+    where
+        I: IntoIterator<Item = ApiResult<Article>>,
+    {
+        Dedup {
+            articles: articles.into_iter(),
+            deduplicator: self,
+        }
+    }
+
+    /// Stream counterpart of `filter`.\
+    /// Requires the `async` feature.
+    #[cfg(feature = "async")]
+    pub fn filter_stream<St>(&mut self, articles: St) -> AsyncDedup<'_, St, S>
+    where
+        St: Stream<Item = ApiResult<Article>> + Unpin,
+    {
+        AsyncDedup {
+            articles,
+            deduplicator: self,
+        }
+    }
+
+    /// The store of the keys seen.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+}
+
+/// Iterator over the new articles, see `Deduplicator::filter`.
+pub struct Dedup<'d, I, S> {
+    articles: I,
+    deduplicator: &'d mut Deduplicator<S>,
+}
+
+impl<I, S> Iterator for Dedup<'_, I, S>
+where
+    I: Iterator<Item = ApiResult<Article>>,
+    S: SeenStore,
+{
+    type Item = ApiResult<Article>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.articles.find(|article| match article {
+            Ok(article) => self.deduplicator.is_new(article),
+            Err(_) => true,
+        })
+    }
+}
+
+/// Stream over the new articles, see `Deduplicator::filter_stream`.\
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub struct AsyncDedup<'d, St, S> {
+    articles: St,
+    deduplicator: &'d mut Deduplicator<S>,
+}
+
+#[cfg(feature = "async")]
+impl<St, S> Stream for AsyncDedup<'_, St, S>
+where
+    St: Stream<Item = ApiResult<Article>> + Unpin,
+    S: SeenStore,
+{
+    type Item = ApiResult<Article>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            match Pin::new(&mut this.articles).poll_next(cx) {
+                Poll::Ready(Some(Ok(article))) => {
+                    if this.deduplicator.is_new(&article) {
+                        return Poll::Ready(Some(Ok(article)));
+                    }
+                }
+                other => return other,
+            }
+        }
+    }
+}
@@ -1,9 +1,25 @@
 use std::collections::HashMap;
+#[cfg(feature = "async")]
+use std::future::Future;
 
-use crate::{
-    newsdata_io::{NewsdataIO, Requests},
-    ApiResult, Json,
-};
+use serde::Serialize;
+
+use super::builder::params_builder;
+use super::date::Timeframe;
+#[cfg(feature = "async")]
+use super::pagination::AsyncPages;
+use super::pagination::{Pages, Paginated};
+use super::query::{encode, ToQuery};
+use super::types::{Category, Country, Flag, Language, PriorityDomain, Sentiment};
+use super::validation::{Validate, Validator, MAX_IDS, MAX_QUERY_CHARS, MAX_SIZE, MAX_VALUES};
+#[cfg(feature = "async")]
+use super::watcher::AsyncWatcher;
+use super::watcher::Watcher;
+#[cfg(feature = "async")]
+use crate::newsdata_io::{AsyncNewsdataIO, AsyncRequests};
+#[cfg(feature = "blocking")]
+use crate::newsdata_io::{NewsdataIO, Requests};
+use crate::{ApiResult, NewsResponse};
 
 /// Trait for the Latest News API.
 pub trait LatestNews {
@@ -15,110 +31,95 @@ pub trait LatestNews {
     ///
     /// # Returns
     ///
-    /// An `ApiResult` containing the JSON response from the API.
-    fn get_latest(&self, params: &GetLatestNewsParams) -> ApiResult<Json>;
+    /// An `ApiResult` containing the `NewsResponse` from the API.
+    fn get_latest(&self, params: &GetLatestNewsParams) -> ApiResult<NewsResponse>;
+
+    /// Iterates over the pages of `get_latest`, following the `nextPage` cursor until it is null.
+    ///
+    /// Use `max_pages` or `max_articles` on the returned `Pages` to cap the API credits spent,
+    /// and `articles` to iterate over the articles instead of the pages.
+    fn get_latest_pages(&self, params: &GetLatestNewsParams) -> Pages<'_, Self, GetLatestNewsParams>
+    where
+        Self: Sized,
+    {
+        Pages::new(self, params.clone(), Self::get_latest)
+    }
+
+    /// Watches `get_latest`, returning only the articles not seen by a previous poll.
+    ///
+    /// The watcher owns a clone of the client.\
+    /// Use `run` to receive the new articles through a callback, `spawn` through a channel,
+    /// and `shutdown_handle` to stop it.
+    fn watch_latest(&self, params: &GetLatestNewsParams) -> Watcher<Self, GetLatestNewsParams>
+    where
+        Self: Sized + Clone,
+    {
+        Watcher::new(self.clone(), params, Self::get_latest)
+    }
 }
 
+#[cfg(feature = "blocking")]
 impl LatestNews for NewsdataIO {
-    fn get_latest(&self, params: &GetLatestNewsParams) -> ApiResult<Json> {
-        let mut query_params = HashMap::new();
+    fn get_latest(&self, params: &GetLatestNewsParams) -> ApiResult<NewsResponse> {
+        params.validate()?;
+        self.get_as("latest", Some(params.to_query()))
+    }
+}
 
-        if let Some(id) = &params.id {
-            query_params.insert("id".to_string(), id.join(","));
-        }
-        if let Some(q) = &params.q {
-            query_params.insert("q".to_string(), q.clone());
-        }
-        if let Some(q_in_title) = &params.q_in_title {
-            query_params.insert("qInTitle".to_string(), q_in_title.clone());
-        }
-        if let Some(q_in_meta) = &params.q_in_meta {
-            query_params.insert("qInMeta".to_string(), q_in_meta.clone());
-        }
-        if let Some(timeframe) = &params.timeframe {
-            query_params.insert("timeframe".to_string(), timeframe.clone());
-        }
-        if let Some(country) = &params.country {
-            query_params.insert("country".to_string(), country.join(","));
-        }
-        if let Some(category) = &params.category {
-            query_params.insert("category".to_string(), category.join(","));
-        }
-        if let Some(exclude_category) = &params.exclude_category {
-            query_params.insert("excludecategory".to_string(), exclude_category.join(","));
-        }
-        if let Some(language) = &params.language {
-            query_params.insert("language".to_string(), language.clone());
-        }
-        if let Some(tag) = &params.tag {
-            query_params.insert("tag".to_string(), tag.clone());
-        }
-        if let Some(sentiment) = &params.sentiment {
-            query_params.insert("sentiment".to_string(), sentiment.clone());
-        }
-        if let Some(region) = &params.region {
-            query_params.insert("region".to_string(), region.clone());
-        }
-        if let Some(domain) = &params.domain {
-            query_params.insert("domain".to_string(), domain.clone());
-        }
-        if let Some(exclude_domain) = &params.exclude_domain {
-            query_params.insert("excludedomain".to_string(), exclude_domain.clone());
-        }
-        if let Some(domain_url) = &params.domain_url {
-            query_params.insert("domainurl".to_string(), domain_url.clone());
-        }
-        if let Some(exclude_field) = &params.exclude_field {
-            query_params.insert("excludefield".to_string(), exclude_field.clone());
-        }
-        if let Some(priority_domain) = &params.priority_domain {
-            query_params.insert("prioritydomain".to_string(), priority_domain.clone());
-        }
-        if let Some(timezone) = &params.timezone {
-            query_params.insert("timezone".to_string(), timezone.clone());
-        }
-        if let Some(full_content) = &params.full_content {
-            println!("full_content: {}", full_content.value().to_string());
-            query_params.insert("full_content".to_string(), full_content.value().to_string());
-        }
-        if let Some(image) = &params.image {
-            query_params.insert("image".to_string(), image.value().to_string());
-        }
-        if let Some(video) = &params.video {
-            query_params.insert("video".to_string(), video.value().to_string());
-        }
-        if let Some(size) = &params.size {
-            query_params.insert("size".to_string(), size.to_string());
-        }
-        if let Some(page) = &params.page {
-            query_params.insert("page".to_string(), page.to_string());
-        }
+/// Async counterpart of `LatestNews`.\
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub trait AsyncLatestNews {
+    /// Get the latest news articles.
+    ///
+    /// # Arguments
+    ///
+    /// * `params`: The parameters for the request.
+    ///
+    /// # Returns
+    ///
+    /// An `ApiResult` containing the `NewsResponse` from the API.
+    fn get_latest(
+        &self,
+        params: &GetLatestNewsParams,
+    ) -> impl Future<Output = ApiResult<NewsResponse>> + Send;
 
-        self.get("latest", Some(query_params))
+    /// Streams the pages of `get_latest`, following the `nextPage` cursor until it is null.
+    ///
+    /// Use `max_pages` or `max_articles` on the returned `AsyncPages` to cap the API credits spent,
+    /// and `articles` to stream the articles instead of the pages.
+    fn get_latest_pages(&self, params: &GetLatestNewsParams) -> AsyncPages<'_, GetLatestNewsParams>
+    where
+        Self: Sized + Sync,
+    {
+        AsyncPages::new(params.clone(), move |params| {
+            Box::pin(async move { self.get_latest(&params).await })
+        })
     }
-}
 
-/// Enum for representing boolean values as strings.
-#[derive(Debug)]
-pub enum Flag {
-    /// False value.
-    False,
-    /// True value.
-    True,
+    /// Watches `get_latest`, streaming only the articles not seen by a previous poll.
+    ///
+    /// Use `shutdown_handle` on the returned `AsyncWatcher` to end the stream.
+    fn watch_latest(&self, params: &GetLatestNewsParams) -> AsyncWatcher<'_, GetLatestNewsParams>
+    where
+        Self: Sized + Sync,
+    {
+        AsyncWatcher::new(params, move |params| {
+            Box::pin(async move { self.get_latest(&params).await })
+        })
+    }
 }
 
-impl Flag {
-    /// Returns the string representation of the flag.
-    fn value(&self) -> &str {
-        match self {
-            Flag::True => "1",
-            Flag::False => "0",
-        }
+#[cfg(feature = "async")]
+impl AsyncLatestNews for AsyncNewsdataIO {
+    async fn get_latest(&self, params: &GetLatestNewsParams) -> ApiResult<NewsResponse> {
+        params.validate()?;
+        self.get_as("latest", Some(params.to_query())).await
     }
 }
 
 /// Parameters for the `get_latest` method.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct GetLatestNewsParams {
     /// Unique identifier of the news article.\
     /// Max no. of id could be added: 50
@@ -130,32 +131,35 @@ pub struct GetLatestNewsParams {
     /// Keywords to search for in the title of the news articles.\
     /// Max characters: 512.\
     /// Exclusive with q and q_in_meta
+    #[serde(rename = "qInTitle")]
     pub q_in_title: Option<String>,
     /// Keywords to search for in the meta description of the news articles.\
     /// Max characters: 512.\
     /// Exclusive with q and q_in_title
+    #[serde(rename = "qInMeta")]
     pub q_in_meta: Option<String>,
     /// Timeframe for the news articles.\
-    /// Only hours or minutes is permitted.\
-    /// Examples: 6 for 6 hours, 15m for 15 min
-    pub timeframe: Option<String>,
+    /// Only hours (1 to 48) or minutes (1 to 2880) is permitted.\
+    /// Sent as 6 for 6 hours, 15m for 15 min
+    pub timeframe: Option<Timeframe>,
     /// Country code for the news articles.\
     /// Max no. of country could be added: 5.\
     /// Examples: "hk", "us", "wo"
-    pub country: Option<Vec<String>>,
+    pub country: Option<Vec<Country>>,
     /// Category for the news articles.\
     /// Max no. of category could be added: 5.\
     /// Exclusive with exclude_category.\
     /// Possible values: "business", "crime", "domestic", "education", "entertainment", "environment", "food", "health", "lifestyle", "other", "politics", "science", "sports", "technology", "top", "tourism", "world"
-    pub category: Option<Vec<String>>,
+    pub category: Option<Vec<Category>>,
     /// Category to exclude from the results.\
     /// Max no. of exclude_category could be added: 5.\
     /// Exclusive with category.\
     /// Possible values: "business", "crime", "domestic", "education", "entertainment", "environment", "food", "health", "lifestyle", "other", "politics", "science", "sports", "technology", "top", "tourism", "world"
-    pub exclude_category: Option<Vec<String>>,
+    #[serde(rename = "excludecategory")]
+    pub exclude_category: Option<Vec<Category>>,
     /// Language code for the news articles.\
     /// Max no. of language could be added: 5.
-    pub language: Option<String>,
+    pub language: Option<Vec<Language>>,
     /// Tag for the news articles.\
     /// Max no. of tag could be added: 5.\
     /// **Available only for Professional and Corporate users**\
@@ -164,7 +168,7 @@ pub struct GetLatestNewsParams {
     /// Sentiment for the news articles.\
     /// Possible values: "positive", "negative", "neutral".\
     /// **Available only for Professional and Corporate users**
-    pub sentiment: Option<String>,
+    pub sentiment: Option<Sentiment>,
     /// Region for the news articles.\
     /// Max no. of region could be added: 5.\
     /// **Available only for Corporate users**
@@ -176,18 +180,22 @@ pub struct GetLatestNewsParams {
     /// Domain to exclude from the results.\
     /// Max no. of domain could be added: 5.\
     /// Possible values in [here](https://newsdata.io/documentation/#latest-news)
+    #[serde(rename = "excludedomain")]
     pub exclude_domain: Option<String>,
     /// Domain URL for the news articles.\
     /// Max no. of domain could be added: 5.
+    #[serde(rename = "domainurl")]
     pub domain_url: Option<String>,
     /// Field to exclude from the results.\
     /// "article_id" is not excludable in response
+    #[serde(rename = "excludefield")]
     pub exclude_field: Option<String>,
     /// Priority domain for the news articles.\
     /// Top: Fetches news articles from the top 10% of the news domains\
     /// Medium: Fetches news articles from the top 30% of the news domains. It means it already includes all the news articles of "top" priority.\
     /// Low: Fetches news articles from the top 50% of the news domains. It means it already includes all the news articles of "top" and "medium" priorities.
-    pub priority_domain: Option<String>,
+    #[serde(rename = "prioritydomain")]
+    pub priority_domain: Option<PriorityDomain>,
     /// Timezone for the news articles.
     pub timezone: Option<String>,
     /// Whether to include full content in the results.
@@ -204,61 +212,79 @@ pub struct GetLatestNewsParams {
     pub page: Option<String>,
 }
 
-impl GetLatestNewsParams {
-    /// Creates a new `GetLatestNewsParams` with default values.
-    ///
-    /// This method sets the default values for all parameters, which are:
-    ///
-    /// * `id`: `None`
-    /// * `q`: `None`
-    /// * `q_in_title`: `None`
-    /// * `q_in_meta`: `None`
-    /// * `timeframe`: `None`
-    /// * `country`: `None`
-    /// * `category`: `None`
-    /// * `exclude_category`: `None`
-    /// * `language`: `None`
-    /// * `tag`: `None`
-    /// * `sentiment`: `None`
-    /// * `region`: `None`
-    /// * `domain`: `None`
-    /// * `exclude_domain`: `None`
-    /// * `domain_url`: `None`
-    /// * `exclude_field`: `None`
-    /// * `priority_domain`: `None`
-    /// * `timezone`: `None`
-    /// * `full_content`: `Some(Flag::False)`
-    /// * `image`: `Some(Flag::False)`
-    /// * `video`: `Some(Flag::False)`
-    /// * `size`: `None`
-    /// * `page`: `None`
-    ///
-    /// This allows you to easily create a `GetLatestNewsParams` object without having to specify all the parameters manually.
-    pub fn default() -> Self {
-        GetLatestNewsParams {
-            id: None,
-            q: None,
-            q_in_title: None,
-            q_in_meta: None,
-            timeframe: None,
-            country: None,
-            category: None,
-            exclude_category: None,
-            language: None,
-            tag: None,
-            sentiment: None,
-            region: None,
-            domain: None,
-            exclude_domain: None,
-            domain_url: None,
-            exclude_field: None,
-            priority_domain: None,
-            timezone: None,
-            full_content: None,
-            image: None,
-            video: None,
-            size: None,
-            page: None,
-        }
+impl Paginated for GetLatestNewsParams {
+    fn set_page(&mut self, page: Option<String>) {
+        self.page = page;
+    }
+}
+
+params_builder! {
+    /// Builder of `GetLatestNewsParams`, see `GetLatestNewsParams::builder`.
+    GetLatestNewsParamsBuilder => GetLatestNewsParams {
+        values id: String,
+        string q,
+        string q_in_title,
+        string q_in_meta,
+        parsed timeframe: Timeframe,
+        values country: Country,
+        values category: Category,
+        values exclude_category: Category,
+        values language: Language,
+        joined tag,
+        parsed sentiment: Sentiment,
+        joined region,
+        joined domain,
+        joined exclude_domain,
+        joined domain_url,
+        joined exclude_field,
+        parsed priority_domain: PriorityDomain,
+        string timezone,
+        value full_content: Flag,
+        value image: Flag,
+        value video: Flag,
+        value size: i32,
+        string page,
+    }
+}
+
+impl Validate for GetLatestNewsParams {
+    fn validate(&self) -> ApiResult<()> {
+        Validator::new()
+            .max_values("id", self.id.as_deref(), MAX_IDS)
+            .max_chars("q", self.q.as_deref(), MAX_QUERY_CHARS)
+            .max_chars("q_in_title", self.q_in_title.as_deref(), MAX_QUERY_CHARS)
+            .max_chars("q_in_meta", self.q_in_meta.as_deref(), MAX_QUERY_CHARS)
+            .exclusive(&[
+                ("q", self.q.is_some()),
+                ("q_in_title", self.q_in_title.is_some()),
+                ("q_in_meta", self.q_in_meta.is_some()),
+            ])
+            .max_values("country", self.country.as_deref(), MAX_VALUES)
+            .max_values("category", self.category.as_deref(), MAX_VALUES)
+            .max_values(
+                "exclude_category",
+                self.exclude_category.as_deref(),
+                MAX_VALUES,
+            )
+            .exclusive(&[
+                ("category", self.category.is_some()),
+                ("exclude_category", self.exclude_category.is_some()),
+            ])
+            .max_values("language", self.language.as_deref(), MAX_VALUES)
+            .max_joined_values("tag", self.tag.as_deref(), MAX_VALUES)
+            .max_joined_values("region", self.region.as_deref(), MAX_VALUES)
+            .max_joined_values("domain", self.domain.as_deref(), MAX_VALUES)
+            .max_joined_values("exclude_domain", self.exclude_domain.as_deref(), MAX_VALUES)
+            .max_joined_values("domain_url", self.domain_url.as_deref(), MAX_VALUES)
+            .not_empty_joined("exclude_field", self.exclude_field.as_deref())
+            .check("timeframe", self.timeframe.as_ref().map(Timeframe::check))
+            .range("size", self.size, 1, MAX_SIZE)
+            .finish()
+    }
+}
+
+impl ToQuery for GetLatestNewsParams {
+    fn to_query(&self) -> HashMap<String, String> {
+        encode(self)
     }
 }
@@ -1,13 +1,56 @@
+mod archive_range;
+mod builder;
 mod crypto_news;
+mod date;
+mod dedup;
 mod latest_news;
 mod news_archive;
 mod news_sources;
+mod pagination;
+mod query;
+mod types;
+mod validation;
+mod watcher;
 
+pub use archive_range::{ArchiveRange, RangeProgress, Window, DEFAULT_RANGE_SEEN_CAPACITY};
+pub use builder::IntoValues;
 pub use crypto_news::CryptoNews;
 pub use crypto_news::GetCryptoNewsParams;
+pub use crypto_news::GetCryptoNewsParamsBuilder;
+pub(crate) use date::days_from_civil;
+pub use date::{ArchiveDate, Timeframe, MAX_TIMEFRAME_HOURS, MAX_TIMEFRAME_MINUTES};
+pub use dedup::{Dedup, DedupKey, Deduplicator, LruStore, SeenStore};
 pub use latest_news::GetLatestNewsParams;
+pub use latest_news::GetLatestNewsParamsBuilder;
 pub use latest_news::LatestNews;
 pub use news_archive::GetNewsArchiveParams;
+pub use news_archive::GetNewsArchiveParamsBuilder;
 pub use news_archive::NewsArchive;
 pub use news_sources::GetNewsSourcesParams;
+pub use news_sources::GetNewsSourcesParamsBuilder;
 pub use news_sources::NewsSources;
+pub use pagination::{Articles, Pages, Paginated};
+#[cfg(feature = "async")]
+pub use pagination::{AsyncArticles, AsyncPages};
+pub use query::ToQuery;
+pub use types::{Category, Country, Flag, Language, PriorityDomain, Sentiment};
+pub use validation::{Validate, MAX_IDS, MAX_QUERY_CHARS, MAX_SIZE, MAX_VALUES};
+pub use watcher::{
+    Shutdown, WatchReceiver, Watcher, DEFAULT_MAX_PAGES_PER_POLL, DEFAULT_SEEN_CAPACITY,
+    DEFAULT_WATCH_INTERVAL,
+};
+
+#[cfg(feature = "async")]
+pub use archive_range::AsyncArchiveRange;
+#[cfg(feature = "async")]
+pub use crypto_news::AsyncCryptoNews;
+#[cfg(feature = "async")]
+pub use dedup::AsyncDedup;
+#[cfg(feature = "async")]
+pub use latest_news::AsyncLatestNews;
+#[cfg(feature = "async")]
+pub use news_archive::AsyncNewsArchive;
+#[cfg(feature = "async")]
+pub use news_sources::AsyncNewsSources;
+#[cfg(feature = "async")]
+pub use watcher::AsyncWatcher;
@@ -1,9 +1,26 @@
 use std::collections::HashMap;
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::ops::RangeInclusive;
 
-use crate::{
-    newsdata_io::{NewsdataIO, Requests},
-    ApiResult, Json,
-};
+use serde::Serialize;
+
+#[cfg(feature = "async")]
+use super::archive_range::AsyncArchiveRange;
+use super::archive_range::{ArchiveRange, Window};
+use super::builder::params_builder;
+use super::date::ArchiveDate;
+#[cfg(feature = "async")]
+use super::pagination::AsyncPages;
+use super::pagination::{Pages, Paginated};
+use super::query::{encode, ToQuery};
+use super::types::{Category, Country, Flag, Language, PriorityDomain};
+use super::validation::{Validate, Validator, MAX_IDS, MAX_QUERY_CHARS, MAX_SIZE, MAX_VALUES};
+#[cfg(feature = "async")]
+use crate::newsdata_io::{AsyncNewsdataIO, AsyncRequests};
+#[cfg(feature = "blocking")]
+use crate::newsdata_io::{NewsdataIO, Requests};
+use crate::{ApiResult, NewsResponse};
 
 /// Trait for the News Archive API.
 pub trait NewsArchive {
@@ -15,158 +32,132 @@ pub trait NewsArchive {
     ///
     /// # Returns
     ///
-    /// An `ApiResult` containing the JSON response from the API.
-    fn get_news_archive(&self, params: &GetNewsArchiveParams) -> ApiResult<Json>;
-}
-
-impl NewsArchive for NewsdataIO {
-    fn get_news_archive(&self, params: &GetNewsArchiveParams) -> ApiResult<Json> {
-        let mut query_params = HashMap::new();
-
-        // Add id parameter to query params
-        if let Some(id) = &params.id {
-            query_params.insert("id".to_string(), id.join(","));
-        }
-
-        // Add from_date parameter to query params
-        if let Some(from_date) = &params.from_date {
-            query_params.insert("from_date".to_string(), from_date.clone());
-        }
-
-        // Add to_date parameter to query params
-        if let Some(to_date) = &params.to_date {
-            query_params.insert("to_date".to_string(), to_date.clone());
-        }
-
-        // Add q parameter to query params
-        if let Some(q) = &params.q {
-            query_params.insert("q".to_string(), q.clone());
-        }
-
-        // Add q_in_title parameter to query params
-        if let Some(q_in_title) = &params.q_in_title {
-            query_params.insert("qInTitle".to_string(), q_in_title.clone());
-        }
-
-        // Add q_in_meta parameter to query params
-        if let Some(q_in_meta) = &params.q_in_meta {
-            query_params.insert("qInMeta".to_string(), q_in_meta.clone());
-        }
-
-        // Add country parameter to query params
-        if let Some(country) = &params.country {
-            query_params.insert("country".to_string(), country.join(","));
-        }
-
-        // Add category parameter to query params
-        if let Some(category) = &params.category {
-            query_params.insert("category".to_string(), category.join(","));
-        }
-
-        // Add exclude_category parameter to query params
-        if let Some(exclude_category) = &params.exclude_category {
-            query_params.insert("excludecategory".to_string(), exclude_category.join(","));
-        }
-
-        // Add language parameter to query params
-        if let Some(language) = &params.language {
-            query_params.insert("language".to_string(), language.clone());
-        }
+    /// An `ApiResult` containing the `NewsResponse` from the API.
+    fn get_news_archive(&self, params: &GetNewsArchiveParams) -> ApiResult<NewsResponse>;
 
-        // Add domain parameter to query params
-        if let Some(domain) = &params.domain {
-            query_params.insert("domain".to_string(), domain.clone());
-        }
-
-        // Add exclude_domain parameter to query params
-        if let Some(exclude_domain) = &params.exclude_domain {
-            query_params.insert("excludedomain".to_string(), exclude_domain.clone());
-        }
-
-        // Add domain_url parameter to query params
-        if let Some(domain_url) = &params.domain_url {
-            query_params.insert("domainurl".to_string(), domain_url.clone());
-        }
-
-        // Add exclude_field parameter to query params
-        if let Some(exclude_field) = &params.exclude_field {
-            query_params.insert("excludefield".to_string(), exclude_field.clone());
-        }
-
-        // Add priority_domain parameter to query params
-        if let Some(priority_domain) = &params.priority_domain {
-            query_params.insert("prioritydomain".to_string(), priority_domain.clone());
-        }
-
-        // Add timezone parameter to query params
-        if let Some(timezone) = &params.timezone {
-            query_params.insert("timezone".to_string(), timezone.clone());
-        }
-
-        // Add full_content parameter to query params
-        if let Some(full_content) = &params.full_content {
-            println!("full_content: {}", full_content.value().to_string());
-            query_params.insert("full_content".to_string(), full_content.value().to_string());
-        }
-
-        // Add image parameter to query params
-        if let Some(image) = &params.image {
-            query_params.insert("image".to_string(), image.value().to_string());
-        }
-
-        // Add video parameter to query params
-        if let Some(video) = &params.video {
-            query_params.insert("video".to_string(), video.value().to_string());
-        }
-
-        // Add size parameter to query params
-        if let Some(size) = &params.size {
-            query_params.insert("size".to_string(), size.to_string());
-        }
+    /// Iterates over the pages of `get_news_archive`, following the `nextPage` cursor until it is null.
+    ///
+    /// Use `max_pages` or `max_articles` on the returned `Pages` to cap the API credits spent,
+    /// and `articles` to iterate over the articles instead of the pages.
+    fn get_news_archive_pages(
+        &self,
+        params: &GetNewsArchiveParams,
+    ) -> Pages<'_, Self, GetNewsArchiveParams>
+    where
+        Self: Sized,
+    {
+        Pages::new(self, params.clone(), Self::get_news_archive)
+    }
 
-        // Add page parameter to query params
-        if let Some(page) = &params.page {
-            query_params.insert("page".to_string(), page.to_string());
-        }
+    /// Iterates over the articles of `get_news_archive` from `range.start()` to `range.end()`,
+    /// sending one paginated query per `window` to stay under the result caps of wide ranges.
+    ///
+    /// The `from_date`, `to_date` and `page` of `params` are replaced by the ones of each window,
+    /// and the articles already returned are dropped, among the last `seen_capacity` ones.\
+    /// Use `on_progress` on the returned `ArchiveRange` to follow the backfill,
+    /// and `max_pages_per_window` to cap the API credits spent.
+    fn get_news_archive_range(
+        &self,
+        params: &GetNewsArchiveParams,
+        range: RangeInclusive<ArchiveDate>,
+        window: Window,
+    ) -> ArchiveRange<'_, Self>
+    where
+        Self: Sized,
+    {
+        ArchiveRange::new(self, params, range, window, Self::get_news_archive)
+    }
+}
 
+#[cfg(feature = "blocking")]
+impl NewsArchive for NewsdataIO {
+    fn get_news_archive(&self, params: &GetNewsArchiveParams) -> ApiResult<NewsResponse> {
+        params.validate()?;
         // Make the GET request to the archive endpoint
-        self.get("archive", Some(query_params))
+        self.get_as("archive", Some(params.to_query()))
     }
 }
 
-/// Enum for representing boolean values as strings.
-#[derive(Debug)]
-pub enum Flag {
-    /// False value.
-    False,
-    /// True value.
-    True,
+/// Async counterpart of `NewsArchive`.\
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub trait AsyncNewsArchive {
+    /// Get news articles from the archive.
+    ///
+    /// # Arguments
+    ///
+    /// * `params`: The parameters for the request.
+    ///
+    /// # Returns
+    ///
+    /// An `ApiResult` containing the `NewsResponse` from the API.
+    fn get_news_archive(
+        &self,
+        params: &GetNewsArchiveParams,
+    ) -> impl Future<Output = ApiResult<NewsResponse>> + Send;
+
+    /// Streams the pages of `get_news_archive`, following the `nextPage` cursor until it is null.
+    ///
+    /// Use `max_pages` or `max_articles` on the returned `AsyncPages` to cap the API credits spent,
+    /// and `articles` to stream the articles instead of the pages.
+    fn get_news_archive_pages(
+        &self,
+        params: &GetNewsArchiveParams,
+    ) -> AsyncPages<'_, GetNewsArchiveParams>
+    where
+        Self: Sized + Sync,
+    {
+        AsyncPages::new(params.clone(), move |params| {
+            Box::pin(async move { self.get_news_archive(&params).await })
+        })
+    }
+
+    /// Streams the articles of `get_news_archive` from `range.start()` to `range.end()`,
+    /// sending one paginated query per `window` to stay under the result caps of wide ranges.
+    ///
+    /// The `from_date`, `to_date` and `page` of `params` are replaced by the ones of each window,
+    /// and the articles already returned are dropped, among the last `seen_capacity` ones.\
+    /// Use `on_progress` on the returned `AsyncArchiveRange` to follow the backfill,
+    /// and `max_pages_per_window` to cap the API credits spent.
+    fn get_news_archive_range(
+        &self,
+        params: &GetNewsArchiveParams,
+        range: RangeInclusive<ArchiveDate>,
+        window: Window,
+    ) -> AsyncArchiveRange<'_>
+    where
+        Self: Sized + Sync,
+    {
+        AsyncArchiveRange::new(params, range, window, move |params| {
+            Box::pin(async move { self.get_news_archive(&params).await })
+        })
+    }
 }
 
-impl Flag {
-    /// Returns the string representation of the flag.
-    fn value(&self) -> &str {
-        match self {
-            Flag::True => "1",
-            Flag::False => "0",
-        }
+#[cfg(feature = "async")]
+impl AsyncNewsArchive for AsyncNewsdataIO {
+    async fn get_news_archive(&self, params: &GetNewsArchiveParams) -> ApiResult<NewsResponse> {
+        params.validate()?;
+        // Make the GET request to the archive endpoint
+        self.get_as("archive", Some(params.to_query())).await
     }
 }
 
 /// Parameters for the `get_news_archive` method.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct GetNewsArchiveParams {
     /// Unique identifier of the news article.\
     /// Max no. of id could be added: 50
     pub id: Option<Vec<String>>,
     /// Start date for the news articles.\
     /// If not specified, the api will fetch the data from the past 1 years if you have professional subscription.\
-    /// Format: YYYY-MM-DD
-    pub from_date: Option<String>,
+    /// Sent as YYYY-MM-DD, or YYYY-MM-DD HH:MM:SS with a time.
+    pub from_date: Option<ArchiveDate>,
     /// End date for the news articles.\
-    /// If not specified, it will be today\
-    /// Format: YYYY-MM-DD
-    pub to_date: Option<String>,
+    /// If not specified, it will be today.\
+    /// Sent as YYYY-MM-DD, or YYYY-MM-DD HH:MM:SS with a time.\
+    /// Must not be before from_date
+    pub to_date: Option<ArchiveDate>,
     /// Keywords to search for in the news articles.\
     /// Max characters: 512.\
     /// Exclusive with q_in_title and q_in_meta
@@ -174,28 +165,31 @@ pub struct GetNewsArchiveParams {
     /// Keywords to search for in the title of the news articles.\
     /// Max characters: 512.\
     /// Exclusive with q and q_in_meta
+    #[serde(rename = "qInTitle")]
     pub q_in_title: Option<String>,
     /// Keywords to search for in the meta description of the news articles.\
     /// Max characters: 512.\
     /// Exclusive with q and q_in_title
+    #[serde(rename = "qInMeta")]
     pub q_in_meta: Option<String>,
     /// Country code for the news articles.\
     /// Max no. of country could be added: 5.\
     /// Examples: "hk", "us", "wo"
-    pub country: Option<Vec<String>>,
+    pub country: Option<Vec<Country>>,
     /// Category for the news articles.\
     /// Max no. of category could be added: 5.\
     /// Exclusive with exclude_category.\
     /// Possible values: "business", "crime", "domestic", "education", "entertainment", "environment", "food", "health", "lifestyle", "other", "politics", "science", "sports", "technology", "top", "tourism", "world"
-    pub category: Option<Vec<String>>,
+    pub category: Option<Vec<Category>>,
     /// Category to exclude from the results.\
     /// Max no. of exclude_category could be added: 5.\
     /// Exclusive with category.\
     /// Possible values: "business", "crime", "domestic", "education", "entertainment", "environment", "food", "health", "lifestyle", "other", "politics", "science", "sports", "technology", "top", "tourism", "world"
-    pub exclude_category: Option<Vec<String>>,
+    #[serde(rename = "excludecategory")]
+    pub exclude_category: Option<Vec<Category>>,
     /// Language code for the news articles.\
     /// Max no. of language could be added: 5.
-    pub language: Option<String>,
+    pub language: Option<Vec<Language>>,
     /// Domain for the news articles.\
     /// Max no. of domain could be added: 5.\
     /// Possible values in [here](https://newsdata.io/documentation/#latest-news)
@@ -203,18 +197,22 @@ pub struct GetNewsArchiveParams {
     /// Domain to exclude from the results.\
     /// Max no. of domain could be added: 5.\
     /// Possible values in [here](https://newsdata.io/documentation/#latest-news)
+    #[serde(rename = "excludedomain")]
     pub exclude_domain: Option<String>,
     /// Domain URL for the news articles.\
     /// Max no. of domain could be added: 5.
+    #[serde(rename = "domainurl")]
     pub domain_url: Option<String>,
     /// Field to exclude from the results.\
     /// "article_id" is not excludable in response
+    #[serde(rename = "excludefield")]
     pub exclude_field: Option<String>,
     /// Priority domain for the news articles.\
     /// Top: Fetches news articles from the top 10% of the news domains\
     /// Medium: Fetches news articles from the top 30% of the news domains. It means it already includes all the news articles of "top" priority.\
     /// Low: Fetches news articles from the top 50% of the news domains. It means it already includes all the news articles of "top" and "medium" priorities.
-    pub priority_domain: Option<String>,
+    #[serde(rename = "prioritydomain")]
+    pub priority_domain: Option<PriorityDomain>,
     /// Timezone for the news articles.
     pub timezone: Option<String>,
     /// Whether to include full content in the results.
@@ -231,57 +229,78 @@ pub struct GetNewsArchiveParams {
     pub page: Option<String>,
 }
 
-impl GetNewsArchiveParams {
-    /// Creates a new `GetNewsArchiveParams` with default values.
-    ///
-    /// This method sets the default values for all parameters, which are:
-    ///
-    /// * `id`: `None`
-    /// * `q`: `None`
-    /// * `q_in_title`: `None`
-    /// * `q_in_meta`: `None`
-    /// * `country`: `None`
-    /// * `category`: `None`
-    /// * `exclude_category`: `None`
-    /// * `language`: `None`
-    /// * `domain`: `None`
-    /// * `exclude_domain`: `None`
-    /// * `domain_url`: `None`
-    /// * `exclude_field`: `None`
-    /// * `priority_domain`: `None`
-    /// * `timezone`: `None`
-    /// * `full_content`: `None`
-    /// * `image`: `None`
-    /// * `video`: `None`
-    /// * `size`: `None`
-    /// * `page`: `None`
-    /// * `from_date`: `None`
-    /// * `to_date`: `None`
-    ///
-    /// This allows you to easily create a `GetNewsArchiveParams` object without having to specify all the parameters manually.
-    pub fn default() -> Self {
-        GetNewsArchiveParams {
-            id: None,
-            q: None,
-            q_in_title: None,
-            q_in_meta: None,
-            country: None,
-            category: None,
-            exclude_category: None,
-            language: None,
-            domain: None,
-            exclude_domain: None,
-            domain_url: None,
-            exclude_field: None,
-            priority_domain: None,
-            timezone: None,
-            full_content: None,
-            image: None,
-            video: None,
-            size: None,
-            page: None,
-            from_date: None,
-            to_date: None,
-        }
+impl Paginated for GetNewsArchiveParams {
+    fn set_page(&mut self, page: Option<String>) {
+        self.page = page;
+    }
+}
+
+params_builder! {
+    /// Builder of `GetNewsArchiveParams`, see `GetNewsArchiveParams::builder`.
+    GetNewsArchiveParamsBuilder => GetNewsArchiveParams {
+        values id: String,
+        parsed from_date: ArchiveDate,
+        parsed to_date: ArchiveDate,
+        string q,
+        string q_in_title,
+        string q_in_meta,
+        values country: Country,
+        values category: Category,
+        values exclude_category: Category,
+        values language: Language,
+        joined domain,
+        joined exclude_domain,
+        joined domain_url,
+        joined exclude_field,
+        parsed priority_domain: PriorityDomain,
+        string timezone,
+        value full_content: Flag,
+        value image: Flag,
+        value video: Flag,
+        value size: i32,
+        string page,
+    }
+}
+
+impl Validate for GetNewsArchiveParams {
+    fn validate(&self) -> ApiResult<()> {
+        Validator::new()
+            .max_values("id", self.id.as_deref(), MAX_IDS)
+            .not_after(
+                ("from_date", self.from_date.as_ref()),
+                ("to_date", self.to_date.as_ref()),
+            )
+            .max_chars("q", self.q.as_deref(), MAX_QUERY_CHARS)
+            .max_chars("q_in_title", self.q_in_title.as_deref(), MAX_QUERY_CHARS)
+            .max_chars("q_in_meta", self.q_in_meta.as_deref(), MAX_QUERY_CHARS)
+            .exclusive(&[
+                ("q", self.q.is_some()),
+                ("q_in_title", self.q_in_title.is_some()),
+                ("q_in_meta", self.q_in_meta.is_some()),
+            ])
+            .max_values("country", self.country.as_deref(), MAX_VALUES)
+            .max_values("category", self.category.as_deref(), MAX_VALUES)
+            .max_values(
+                "exclude_category",
+                self.exclude_category.as_deref(),
+                MAX_VALUES,
+            )
+            .exclusive(&[
+                ("category", self.category.is_some()),
+                ("exclude_category", self.exclude_category.is_some()),
+            ])
+            .max_values("language", self.language.as_deref(), MAX_VALUES)
+            .max_joined_values("domain", self.domain.as_deref(), MAX_VALUES)
+            .max_joined_values("exclude_domain", self.exclude_domain.as_deref(), MAX_VALUES)
+            .max_joined_values("domain_url", self.domain_url.as_deref(), MAX_VALUES)
+            .not_empty_joined("exclude_field", self.exclude_field.as_deref())
+            .range("size", self.size, 1, MAX_SIZE)
+            .finish()
+    }
+}
+
+impl ToQuery for GetNewsArchiveParams {
+    fn to_query(&self) -> HashMap<String, String> {
+        encode(self)
     }
 }
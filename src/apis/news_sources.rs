@@ -1,9 +1,18 @@
 use std::collections::HashMap;
+#[cfg(feature = "async")]
+use std::future::Future;
 
-use crate::{
-    newsdata_io::{NewsdataIO, Requests},
-    ApiResult, Json,
-};
+use serde::Serialize;
+
+use super::builder::params_builder;
+use super::query::{encode, ToQuery};
+use super::types::{Category, Country, Language, PriorityDomain};
+use super::validation::{Validate, Validator, MAX_IDS, MAX_VALUES};
+#[cfg(feature = "async")]
+use crate::newsdata_io::{AsyncNewsdataIO, AsyncRequests};
+#[cfg(feature = "blocking")]
+use crate::newsdata_io::{NewsdataIO, Requests};
+use crate::{ApiResult, SourcesResponse};
 
 /// Trait for the News Sources API.
 pub trait NewsSources {
@@ -15,51 +24,49 @@ pub trait NewsSources {
     ///
     /// # Returns
     ///
-    /// An `ApiResult` containing the JSON response from the API.
-    fn get_news_sources(&self, params: &GetNewsSourcesParams) -> ApiResult<Json>;
+    /// An `ApiResult` containing the `SourcesResponse` from the API.
+    fn get_news_sources(&self, params: &GetNewsSourcesParams) -> ApiResult<SourcesResponse>;
 }
 
+#[cfg(feature = "blocking")]
 impl NewsSources for NewsdataIO {
-    fn get_news_sources(&self, params: &GetNewsSourcesParams) -> ApiResult<Json> {
-        let mut query_params = HashMap::new();
-
-        // Add id parameter to query params
-        if let Some(id) = &params.id {
-            query_params.insert("id".to_string(), id.join(","));
-        }
-
-        // Add country parameter to query params
-        if let Some(country) = &params.country {
-            query_params.insert("country".to_string(), country.join(","));
-        }
-
-        // Add category parameter to query params
-        if let Some(category) = &params.category {
-            query_params.insert("category".to_string(), category.join(","));
-        }
-
-        // Add exclude_category parameter to query params
-        if let Some(exclude_category) = &params.exclude_category {
-            query_params.insert("excludecategory".to_string(), exclude_category.join(","));
-        }
-
-        // Add language parameter to query params
-        if let Some(language) = &params.language {
-            query_params.insert("language".to_string(), language.clone());
-        }
+    fn get_news_sources(&self, params: &GetNewsSourcesParams) -> ApiResult<SourcesResponse> {
+        params.validate()?;
+        // Make the GET request to the sources endpoint
+        self.get_as("sources", Some(params.to_query()))
+    }
+}
 
-        // Add priority_domain parameter to query params
-        if let Some(priority_domain) = &params.priority_domain {
-            query_params.insert("prioritydomain".to_string(), priority_domain.clone());
-        }
+/// Async counterpart of `NewsSources`.\
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub trait AsyncNewsSources {
+    /// Get news sources.
+    ///
+    /// # Arguments
+    ///
+    /// * `params`: The parameters for the request.
+    ///
+    /// # Returns
+    ///
+    /// An `ApiResult` containing the `SourcesResponse` from the API.
+    fn get_news_sources(
+        &self,
+        params: &GetNewsSourcesParams,
+    ) -> impl Future<Output = ApiResult<SourcesResponse>> + Send;
+}
 
+#[cfg(feature = "async")]
+impl AsyncNewsSources for AsyncNewsdataIO {
+    async fn get_news_sources(&self, params: &GetNewsSourcesParams) -> ApiResult<SourcesResponse> {
+        params.validate()?;
         // Make the GET request to the sources endpoint
-        self.get("sources", Some(query_params))
+        self.get_as("sources", Some(params.to_query())).await
     }
 }
 
 /// Parameters for the `get_news_sources` method.
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct GetNewsSourcesParams {
     /// Unique identifier of the news source.\
     /// Max no. of id could be added: 50
@@ -67,47 +74,62 @@ pub struct GetNewsSourcesParams {
     /// Country code for the news sources.\
     /// Max no. of country could be added: 5.\
     /// Examples: "hk", "us", "wo"
-    pub country: Option<Vec<String>>,
+    pub country: Option<Vec<Country>>,
     /// Category for the news sources.\
     /// Max no. of category could be added: 5.\
     /// Possible values: "business", "crime", "domestic", "education", "entertainment", "environment", "food", "health", "lifestyle", "other", "politics", "science", "sports", "technology", "top", "tourism", "world"
-    pub category: Option<Vec<String>>,
+    pub category: Option<Vec<Category>>,
     /// Exclude category for the news sources.\
     /// Max no. of category could be added: 5.\
     /// Exclusive with category.\
     /// Possible values: "business", "crime", "domestic", "education", "entertainment", "environment", "food", "health", "lifestyle", "other", "politics", "science", "sports", "technology", "top", "tourism", "world"
-    pub exclude_category: Option<Vec<String>>,
+    #[serde(rename = "excludecategory")]
+    pub exclude_category: Option<Vec<Category>>,
     /// Language code for the news sources.\
     /// Max no. of language could be added: 5.
-    pub language: Option<String>,
+    pub language: Option<Vec<Language>>,
     /// Priority domain for the news articles.\
     /// Top: Fetches news articles from the top 10% of the news domains\
     /// Medium: Fetches news articles from the top 30% of the news domains. It means it already includes all the news articles of "top" priority.\
     /// Low: Fetches news articles from the top 50% of the news domains. It means it already includes all the news articles of "top" and "medium" priorities.
-    pub priority_domain: Option<String>,
+    #[serde(rename = "prioritydomain")]
+    pub priority_domain: Option<PriorityDomain>,
 }
 
-impl GetNewsSourcesParams {
-    /// Creates a new `GetNewsSourcesParams` with default values.
-    ///
-    /// This method sets the default values for all parameters, which are:
-    ///
-    /// * `id`: `None`
-    /// * `country`: `None`
-    /// * `category`: `None`
-    /// * `exclude_category`: `None`
-    /// * `language`: `None`
-    /// * `priority_domain`: `None`
-    ///
-    /// This allows you to easily create a `GetNewsSourcesParams` object without having to specify all the parameters manually.
-    pub fn default() -> Self {
-        GetNewsSourcesParams {
-            id: None,
-            country: None,
-            category: None,
-            exclude_category: None,
-            language: None,
-            priority_domain: None,
-        }
+params_builder! {
+    /// Builder of `GetNewsSourcesParams`, see `GetNewsSourcesParams::builder`.
+    GetNewsSourcesParamsBuilder => GetNewsSourcesParams {
+        values id: String,
+        values country: Country,
+        values category: Category,
+        values exclude_category: Category,
+        values language: Language,
+        parsed priority_domain: PriorityDomain,
+    }
+}
+
+impl Validate for GetNewsSourcesParams {
+    fn validate(&self) -> ApiResult<()> {
+        Validator::new()
+            .max_values("id", self.id.as_deref(), MAX_IDS)
+            .max_values("country", self.country.as_deref(), MAX_VALUES)
+            .max_values("category", self.category.as_deref(), MAX_VALUES)
+            .max_values(
+                "exclude_category",
+                self.exclude_category.as_deref(),
+                MAX_VALUES,
+            )
+            .exclusive(&[
+                ("category", self.category.is_some()),
+                ("exclude_category", self.exclude_category.is_some()),
+            ])
+            .max_values("language", self.language.as_deref(), MAX_VALUES)
+            .finish()
+    }
+}
+
+impl ToQuery for GetNewsSourcesParams {
+    fn to_query(&self) -> HashMap<String, String> {
+        encode(self)
     }
 }
@@ -0,0 +1,251 @@
+#[cfg(feature = "async")]
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+#[cfg(feature = "async")]
+use futures_core::Stream;
+
+use crate::{ApiResult, Article, NewsResponse};
+
+/// Parameters carrying a `page` cursor, i.e. the ones of paginated endpoints.
+pub trait Paginated: Clone {
+    /// Sets the `page` parameter of the next request.
+    fn set_page(&mut self, page: Option<String>);
+}
+
+/// Pagination progress shared by the blocking and async paginators.
+#[derive(Debug, Clone, Default)]
+struct PageState {
+    max_pages: Option<usize>,
+    max_articles: Option<usize>,
+    pages: usize,
+    articles: usize,
+    done: bool,
+}
+
+impl PageState {
+    /// Whether another page may be requested.
+    fn has_next(&self) -> bool {
+        !self.done
+            && self.max_pages.is_none_or(|max| self.pages < max)
+            && self.max_articles.is_none_or(|max| self.articles < max)
+    }
+
+    /// Applies the limits to a fetched page and returns the cursor of the next one, if any.
+    fn process(&mut self, page: &mut NewsResponse) -> Option<String> {
+        self.pages += 1;
+        if let Some(max) = self.max_articles {
+            page.results.truncate(max.saturating_sub(self.articles));
+        }
+        self.articles += page.results.len();
+        match page.next_page.clone() {
+            Some(next_page) if !page.results.is_empty() => Some(next_page),
+            _ => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+/// Iterator over the pages of a paginated endpoint.
+///
+/// Pages are fetched lazily until `nextPage` is null, an error occurs or one of the limits is reached.
+pub struct Pages<'a, C, P> {
+    client: &'a C,
+    params: P,
+    fetch: fn(&C, &P) -> ApiResult<NewsResponse>,
+    state: PageState,
+}
+
+impl<'a, C, P: Paginated> Pages<'a, C, P> {
+    pub(crate) fn new(
+        client: &'a C,
+        params: P,
+        fetch: fn(&C, &P) -> ApiResult<NewsResponse>,
+    ) -> Self {
+        Self {
+            client,
+            params,
+            fetch,
+            state: PageState::default(),
+        }
+    }
+
+    /// Stops after `max_pages` pages have been fetched.
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.state.max_pages = Some(max_pages);
+        self
+    }
+
+    /// Stops after `max_articles` articles have been returned, truncating the last page.
+    pub fn max_articles(mut self, max_articles: usize) -> Self {
+        self.state.max_articles = Some(max_articles);
+        self
+    }
+
+    /// Turns the pages into an iterator over their articles.
+    pub fn articles(self) -> Articles<'a, C, P> {
+        Articles {
+            pages: self,
+            current: Vec::new().into_iter(),
+        }
+    }
+}
+
+impl<C, P: Paginated> Iterator for Pages<'_, C, P> {
+    type Item = ApiResult<NewsResponse>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.state.has_next() {
+            return None;
+        }
+        match (self.fetch)(self.client, &self.params) {
+            Ok(mut page) => {
+                let next_page = self.state.process(&mut page);
+                if next_page.is_some() {
+                    self.params.set_page(next_page);
+                }
+                Some(Ok(page))
+            }
+            Err(e) => {
+                self.state.done = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// Iterator over the articles of a paginated endpoint, see `Pages::articles`.
+pub struct Articles<'a, C, P> {
+    pages: Pages<'a, C, P>,
+    current: std::vec::IntoIter<Article>,
+}
+
+impl<C, P: Paginated> Iterator for Articles<'_, C, P> {
+    type Item = ApiResult<Article>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(article) = self.current.next() {
+                return Some(Ok(article));
+            }
+            match self.pages.next()? {
+                Ok(page) => self.current = page.results.into_iter(),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub(crate) type PageFuture<'a> = Pin<Box<dyn Future<Output = ApiResult<NewsResponse>> + Send + 'a>>;
+
+/// Async counterpart of `Pages`, implementing `Stream`.\
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub struct AsyncPages<'a, P> {
+    params: P,
+    fetch: Box<dyn Fn(P) -> PageFuture<'a> + Send + Sync + 'a>,
+    pending: Option<PageFuture<'a>>,
+    state: PageState,
+}
+
+#[cfg(feature = "async")]
+impl<'a, P: Paginated> AsyncPages<'a, P> {
+    pub(crate) fn new(params: P, fetch: impl Fn(P) -> PageFuture<'a> + Send + Sync + 'a) -> Self {
+        Self {
+            params,
+            fetch: Box::new(fetch),
+            pending: None,
+            state: PageState::default(),
+        }
+    }
+
+    /// Stops after `max_pages` pages have been fetched.
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.state.max_pages = Some(max_pages);
+        self
+    }
+
+    /// Stops after `max_articles` articles have been returned, truncating the last page.
+    pub fn max_articles(mut self, max_articles: usize) -> Self {
+        self.state.max_articles = Some(max_articles);
+        self
+    }
+
+    /// Turns the pages into a stream of their articles.
+    pub fn articles(self) -> AsyncArticles<'a, P> {
+        AsyncArticles {
+            pages: self,
+            current: Vec::new().into_iter(),
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<P: Paginated + Unpin> Stream for AsyncPages<'_, P> {
+    type Item = ApiResult<NewsResponse>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.pending.is_none() {
+            if !this.state.has_next() {
+                return Poll::Ready(None);
+            }
+            this.pending = Some((this.fetch)(this.params.clone()));
+        }
+        let result = match this.pending.as_mut() {
+            Some(pending) => match pending.as_mut().poll(cx) {
+                Poll::Ready(result) => result,
+                Poll::Pending => return Poll::Pending,
+            },
+            None => return Poll::Ready(None),
+        };
+        this.pending = None;
+        match result {
+            Ok(mut page) => {
+                let next_page = this.state.process(&mut page);
+                if next_page.is_some() {
+                    this.params.set_page(next_page);
+                }
+                Poll::Ready(Some(Ok(page)))
+            }
+            Err(e) => {
+                this.state.done = true;
+                Poll::Ready(Some(Err(e)))
+            }
+        }
+    }
+}
+
+/// Stream over the articles of a paginated endpoint, see `AsyncPages::articles`.\
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub struct AsyncArticles<'a, P> {
+    pages: AsyncPages<'a, P>,
+    current: std::vec::IntoIter<Article>,
+}
+
+#[cfg(feature = "async")]
+impl<P: Paginated + Unpin> Stream for AsyncArticles<'_, P> {
+    type Item = ApiResult<Article>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(article) = this.current.next() {
+                return Poll::Ready(Some(Ok(article)));
+            }
+            match Pin::new(&mut this.pages).poll_next(cx) {
+                Poll::Ready(Some(Ok(page))) => this.current = page.results.into_iter(),
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+
+use log::error;
+use serde::Serialize;
+
+use crate::Json;
+
+/// Parameters which can be encoded into the query parameters of a request.
+pub trait ToQuery {
+    /// Returns the query parameters, keyed by the names expected by the API.
+    fn to_query(&self) -> HashMap<String, String>;
+}
+
+/// Encodes the fields of a serializable struct into query parameters.
+///
+/// Field names are taken from serde, so renames (e.g. `qInTitle`) are declared on the struct.
+/// `None` fields are skipped, lists are joined with commas and booleans become `1`/`0`.
+pub(crate) fn encode<T: Serialize>(params: &T) -> HashMap<String, String> {
+    let fields = match serde_json::to_value(params) {
+        Ok(Json::Object(fields)) => fields,
+        Ok(value) => {
+            error!("Query params must serialize to an object, got: {value}");
+            return HashMap::new();
+        }
+        Err(e) => {
+            error!("Failed to serialize query params: {e}");
+            return HashMap::new();
+        }
+    };
+    fields
+        .into_iter()
+        .filter_map(|(key, value)| encode_value(value).map(|value| (key, value)))
+        .collect()
+}
+
+fn encode_value(value: Json) -> Option<String> {
+    match value {
+        Json::Null => None,
+        Json::Bool(value) => Some(if value { "1" } else { "0" }.to_string()),
+        Json::Number(value) => Some(value.to_string()),
+        Json::String(value) => Some(value),
+        Json::Array(values) => Some(
+            values
+                .into_iter()
+                .filter_map(encode_value)
+                .collect::<Vec<_>>()
+                .join(","),
+        ),
+        Json::Object(_) => Some(value.to_string()),
+    }
+}
@@ -0,0 +1,486 @@
+use std::convert::Infallible;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Error;
+
+/// Declares an enum of the documented values of a parameter, with an `Other` variant for the undocumented ones.
+///
+/// Parsing is case insensitive. `FromStr` and `Deserialize` keep unknown values in `Other`,
+/// so that values added by the API still decode, while `parse_strict` and `TryFrom`,
+/// used by the params builders, fail with an `Error::InvalidParameter` of `$field` on them.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident($field:literal) {
+            $($variant:ident => $value:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $(
+                #[doc = concat!("`", $value, "`")]
+                $variant,
+            )*
+            /// A value not covered by the other variants, sent as is.\
+            /// Produced by `FromStr` and `Deserialize` for unknown values.
+            Other(String),
+        }
+
+        impl $name {
+            /// Every documented value.
+            pub const ALL: &'static [$name] = &[$($name::$variant),*];
+
+            /// Returns the value sent to the API.
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Other(value) => value,
+                }
+            }
+
+            /// Parses a documented value, failing on unknown values instead of keeping them in `Other`,
+            /// e.g. to catch typos.
+            pub fn parse_strict(s: &str) -> Result<Self, Error> {
+                let value = s.trim();
+                $(
+                    if value.eq_ignore_ascii_case($value) {
+                        return Ok($name::$variant);
+                    }
+                )*
+                Err(Error::invalid_parameter(
+                    $field,
+                    format!(
+                        concat!("unknown value `{}`, use `", stringify!($name), "::Other` to send an undocumented one"),
+                        value
+                    ),
+                ))
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = Infallible;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok($name::parse_strict(s).unwrap_or_else(|_| $name::Other(s.to_string())))
+            }
+        }
+
+        impl Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = Error;
+
+            fn try_from(value: &str) -> Result<Self, Self::Error> {
+                $name::parse_strict(value)
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = Error;
+
+            fn try_from(value: String) -> Result<Self, Self::Error> {
+                $name::parse_strict(&value)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let value = String::deserialize(deserializer)?;
+                match $name::parse_strict(&value) {
+                    Ok(parsed) => Ok(parsed),
+                    Err(_) => Ok($name::Other(value)),
+                }
+            }
+        }
+    };
+}
+
+string_enum! {
+    /// Category of the news articles.
+    Category("category") {
+        Business => "business",
+        Crime => "crime",
+        Domestic => "domestic",
+        Education => "education",
+        Entertainment => "entertainment",
+        Environment => "environment",
+        Food => "food",
+        Health => "health",
+        Lifestyle => "lifestyle",
+        Miscellaneous => "other",
+        Politics => "politics",
+        Science => "science",
+        Sports => "sports",
+        Technology => "technology",
+        Top => "top",
+        Tourism => "tourism",
+        World => "world",
+    }
+}
+
+string_enum! {
+    /// Country of the news articles, as the code used by the API.
+    Country("country") {
+        Afghanistan => "af",
+        Albania => "al",
+        Algeria => "dz",
+        Andorra => "ad",
+        Angola => "ao",
+        Argentina => "ar",
+        Armenia => "am",
+        Australia => "au",
+        Austria => "at",
+        Azerbaijan => "az",
+        Bahamas => "bs",
+        Bahrain => "bh",
+        Bangladesh => "bd",
+        Barbados => "bb",
+        Belarus => "by",
+        Belgium => "be",
+        Belize => "bz",
+        Benin => "bj",
+        Bermuda => "bm",
+        Bhutan => "bt",
+        Bolivia => "bo",
+        BosniaAndHerzegovina => "ba",
+        Botswana => "bw",
+        Brazil => "br",
+        Brunei => "bn",
+        Bulgaria => "bg",
+        BurkinaFaso => "bf",
+        Burundi => "bi",
+        Cambodia => "kh",
+        Cameroon => "cm",
+        Canada => "ca",
+        CapeVerde => "cv",
+        CaymanIslands => "ky",
+        CentralAfricanRepublic => "cf",
+        Chad => "td",
+        Chile => "cl",
+        China => "cn",
+        Colombia => "co",
+        Comoros => "km",
+        Congo => "cg",
+        CookIslands => "ck",
+        CostaRica => "cr",
+        Croatia => "hr",
+        Cuba => "cu",
+        Curacao => "cw",
+        Cyprus => "cy",
+        CzechRepublic => "cz",
+        Denmark => "dk",
+        Djibouti => "dj",
+        Dominica => "dm",
+        DominicanRepublic => "do",
+        DrCongo => "cd",
+        Ecuador => "ec",
+        Egypt => "eg",
+        ElSalvador => "sv",
+        EquatorialGuinea => "gq",
+        Eritrea => "er",
+        Estonia => "ee",
+        Eswatini => "sz",
+        Ethiopia => "et",
+        Fiji => "fj",
+        Finland => "fi",
+        France => "fr",
+        FrenchPolynesia => "pf",
+        Gabon => "ga",
+        Gambia => "gm",
+        Georgia => "ge",
+        Germany => "de",
+        Ghana => "gh",
+        Gibraltar => "gi",
+        Greece => "gr",
+        Grenada => "gd",
+        Guatemala => "gt",
+        Guinea => "gn",
+        Guyana => "gy",
+        Haiti => "ht",
+        Honduras => "hn",
+        HongKong => "hk",
+        Hungary => "hu",
+        Iceland => "is",
+        India => "in",
+        Indonesia => "id",
+        Iran => "ir",
+        Iraq => "iq",
+        Ireland => "ie",
+        Israel => "il",
+        Italy => "it",
+        IvoryCoast => "ci",
+        Jamaica => "jm",
+        Japan => "jp",
+        Jersey => "je",
+        Jordan => "jo",
+        Kazakhstan => "kz",
+        Kenya => "ke",
+        Kiribati => "ki",
+        Kosovo => "xk",
+        Kuwait => "kw",
+        Kyrgyzstan => "kg",
+        Laos => "la",
+        Latvia => "lv",
+        Lebanon => "lb",
+        Lesotho => "ls",
+        Liberia => "lr",
+        Libya => "ly",
+        Liechtenstein => "li",
+        Lithuania => "lt",
+        Luxembourg => "lu",
+        Macau => "mo",
+        Macedonia => "mk",
+        Madagascar => "mg",
+        Malawi => "mw",
+        Malaysia => "my",
+        Maldives => "mv",
+        Mali => "ml",
+        Malta => "mt",
+        MarshallIslands => "mh",
+        Mauritania => "mr",
+        Mauritius => "mu",
+        Mexico => "mx",
+        Micronesia => "fm",
+        Moldova => "md",
+        Monaco => "mc",
+        Mongolia => "mn",
+        Montenegro => "me",
+        Morocco => "ma",
+        Mozambique => "mz",
+        Myanmar => "mm",
+        Namibia => "na",
+        Nauru => "nr",
+        Nepal => "np",
+        Netherlands => "nl",
+        NewCaledonia => "nc",
+        NewZealand => "nz",
+        Nicaragua => "ni",
+        Niger => "ne",
+        Nigeria => "ng",
+        NorthKorea => "kp",
+        Norway => "no",
+        Oman => "om",
+        Pakistan => "pk",
+        Palau => "pw",
+        Palestine => "ps",
+        Panama => "pa",
+        PapuaNewGuinea => "pg",
+        Paraguay => "py",
+        Peru => "pe",
+        Philippines => "ph",
+        Poland => "pl",
+        Portugal => "pt",
+        PuertoRico => "pr",
+        Qatar => "qa",
+        Romania => "ro",
+        Russia => "ru",
+        Rwanda => "rw",
+        SaintLucia => "lc",
+        SintMaarten => "sx",
+        Samoa => "ws",
+        SanMarino => "sm",
+        SaoTomeAndPrincipe => "st",
+        SaudiArabia => "sa",
+        Senegal => "sn",
+        Serbia => "rs",
+        Seychelles => "sc",
+        SierraLeone => "sl",
+        Singapore => "sg",
+        Slovakia => "sk",
+        Slovenia => "si",
+        SolomonIslands => "sb",
+        Somalia => "so",
+        SouthAfrica => "za",
+        SouthKorea => "kr",
+        Spain => "es",
+        SriLanka => "lk",
+        Sudan => "sd",
+        Suriname => "sr",
+        Sweden => "se",
+        Switzerland => "ch",
+        Syria => "sy",
+        Taiwan => "tw",
+        Tajikistan => "tj",
+        Tanzania => "tz",
+        Thailand => "th",
+        TimorLeste => "tl",
+        Togo => "tg",
+        Tonga => "to",
+        TrinidadAndTobago => "tt",
+        Tunisia => "tn",
+        Turkey => "tr",
+        Turkmenistan => "tm",
+        Tuvalu => "tv",
+        Uganda => "ug",
+        Ukraine => "ua",
+        UnitedArabEmirates => "ae",
+        UnitedKingdom => "gb",
+        UnitedStates => "us",
+        Uruguay => "uy",
+        Uzbekistan => "uz",
+        Vanuatu => "vu",
+        Vatican => "va",
+        Venezuela => "ve",
+        Vietnam => "vi",
+        BritishVirginIslands => "vg",
+        World => "wo",
+        Yemen => "ye",
+        Zambia => "zm",
+        Zimbabwe => "zw",
+    }
+}
+
+string_enum! {
+    /// Language of the news articles, as the code used by the API.
+    Language("language") {
+        Afrikaans => "af",
+        Albanian => "sq",
+        Amharic => "am",
+        Arabic => "ar",
+        Armenian => "hy",
+        Assamese => "as",
+        Azerbaijani => "az",
+        Basque => "eu",
+        Belarusian => "be",
+        Bengali => "bn",
+        Bosnian => "bs",
+        Bulgarian => "bg",
+        Burmese => "my",
+        Catalan => "ca",
+        CentralKurdish => "ckb",
+        Chinese => "zh",
+        Croatian => "hr",
+        Czech => "cs",
+        Danish => "da",
+        Dutch => "nl",
+        English => "en",
+        Estonian => "et",
+        Filipino => "pi",
+        Finnish => "fi",
+        French => "fr",
+        Galician => "gl",
+        Georgian => "ka",
+        German => "de",
+        Greek => "el",
+        Gujarati => "gu",
+        Hausa => "ha",
+        Hebrew => "he",
+        Hindi => "hi",
+        Hungarian => "hu",
+        Icelandic => "is",
+        Indonesian => "id",
+        Italian => "it",
+        Japanese => "jp",
+        Kannada => "kn",
+        Kazakh => "kz",
+        Khmer => "kh",
+        Kinyarwanda => "rw",
+        Korean => "ko",
+        Kurdish => "ku",
+        Latvian => "lv",
+        Lithuanian => "lt",
+        Luxembourgish => "lb",
+        Macedonian => "mk",
+        Malay => "ms",
+        Malayalam => "ml",
+        Maltese => "mt",
+        Maori => "mi",
+        Marathi => "mr",
+        Mongolian => "mn",
+        Nepali => "ne",
+        Norwegian => "no",
+        Oriya => "or",
+        Pashto => "ps",
+        Persian => "fa",
+        Polish => "pl",
+        Portuguese => "pt",
+        Punjabi => "pa",
+        Romanian => "ro",
+        Russian => "ru",
+        Samoan => "sm",
+        Serbian => "sr",
+        Shona => "sn",
+        Sindhi => "sd",
+        Sinhala => "si",
+        Slovak => "sk",
+        Slovenian => "sl",
+        Somali => "so",
+        Spanish => "es",
+        Swahili => "sw",
+        Swedish => "sv",
+        Tajik => "tg",
+        Tamil => "ta",
+        Telugu => "te",
+        Thai => "th",
+        TraditionalChinese => "zht",
+        Turkish => "tr",
+        Turkmen => "tk",
+        Ukrainian => "uk",
+        Urdu => "ur",
+        Uzbek => "uz",
+        Vietnamese => "vi",
+        Welsh => "cy",
+    }
+}
+
+string_enum! {
+    /// Priority of the news domains.\
+    /// Top: Fetches news articles from the top 10% of the news domains\
+    /// Medium: Fetches news articles from the top 30% of the news domains. It means it already includes all the news articles of "top" priority.\
+    /// Low: Fetches news articles from the top 50% of the news domains. It means it already includes all the news articles of "top" and "medium" priorities.
+    PriorityDomain("priority_domain") {
+        Top => "top",
+        Medium => "medium",
+        Low => "low",
+    }
+}
+
+string_enum! {
+    /// Sentiment of the news articles.
+    Sentiment("sentiment") {
+        Positive => "positive",
+        Negative => "negative",
+        Neutral => "neutral",
+    }
+}
+
+/// Enum for representing boolean values as strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Flag {
+    /// False value.
+    #[serde(rename = "0")]
+    False,
+    /// True value.
+    #[serde(rename = "1")]
+    True,
+}
+
+impl From<bool> for Flag {
+    fn from(value: bool) -> Self {
+        if value {
+            Flag::True
+        } else {
+            Flag::False
+        }
+    }
+}
+
+impl From<Flag> for bool {
+    fn from(flag: Flag) -> Self {
+        flag == Flag::True
+    }
+}
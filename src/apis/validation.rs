@@ -0,0 +1,213 @@
+use super::date::ArchiveDate;
+use crate::{ApiResult, Error, Violation};
+
+/// Max characters of the `q`, `q_in_title` and `q_in_meta` parameters.
+pub const MAX_QUERY_CHARS: usize = 512;
+/// Max number of values of the `id` parameter.
+pub const MAX_IDS: usize = 50;
+/// Max number of values of the other list parameters, e.g. `country`.
+pub const MAX_VALUES: usize = 5;
+/// Max value of the `size` parameter.
+pub const MAX_SIZE: i32 = 50;
+
+/// Parameters whose documented constraints can be checked before sending the request.
+pub trait Validate {
+    /// Checks every constraint of the parameters.
+    ///
+    /// # Returns
+    ///
+    /// An `Error::InvalidParameter` listing all violations, if any.
+    fn validate(&self) -> ApiResult<()>;
+}
+
+/// Collects the violations of a set of parameters.
+#[derive(Debug, Default)]
+pub(crate) struct Validator {
+    violations: Vec<Violation>,
+}
+
+impl Validator {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    fn violation(&mut self, field: &str, message: String) {
+        self.violations.push(Violation {
+            field: field.to_string(),
+            message,
+        });
+    }
+
+    fn empty(&mut self, field: &str) {
+        self.violation(
+            field,
+            "must not be empty, leave it unset instead".to_string(),
+        );
+    }
+
+    /// At most one of `fields`, given as `(name, is_set)`, may be set.
+    pub(crate) fn exclusive(&mut self, fields: &[(&str, bool)]) -> &mut Self {
+        let set: Vec<&str> = fields
+            .iter()
+            .filter(|(_, is_set)| *is_set)
+            .map(|(name, _)| *name)
+            .collect();
+        if set.len() > 1 {
+            for name in &set {
+                let others: Vec<&str> =
+                    set.iter().filter(|other| *other != name).copied().collect();
+                self.violation(name, format!("exclusive with {}", others.join(", ")));
+            }
+        }
+        self
+    }
+
+    /// A list must hold 1 to `max` values: an empty one would be sent as an empty value.
+    pub(crate) fn max_values<T>(
+        &mut self,
+        field: &str,
+        values: Option<&[T]>,
+        max: usize,
+    ) -> &mut Self {
+        if let Some(values) = values {
+            if values.is_empty() {
+                self.empty(field);
+            } else if values.len() > max {
+                self.violation(
+                    field,
+                    format!("at most {max} values allowed, got {}", values.len()),
+                );
+            }
+        }
+        self
+    }
+
+    /// A comma separated list must hold 1 to `max` values.
+    pub(crate) fn max_joined_values(
+        &mut self,
+        field: &str,
+        value: Option<&str>,
+        max: usize,
+    ) -> &mut Self {
+        if let Some(value) = value {
+            let count = value
+                .split(',')
+                .filter(|value| !value.trim().is_empty())
+                .count();
+            if count == 0 {
+                self.empty(field);
+            } else if count > max {
+                self.violation(field, format!("at most {max} values allowed, got {count}"));
+            }
+        }
+        self
+    }
+
+    /// A comma separated list without a max number of values must not be empty.
+    pub(crate) fn not_empty_joined(&mut self, field: &str, value: Option<&str>) -> &mut Self {
+        self.max_joined_values(field, value, usize::MAX)
+    }
+
+    /// A string may hold at most `max` characters.
+    pub(crate) fn max_chars(&mut self, field: &str, value: Option<&str>, max: usize) -> &mut Self {
+        if let Some(value) = value {
+            let count = value.chars().count();
+            if count > max {
+                self.violation(
+                    field,
+                    format!("at most {max} characters allowed, got {count}"),
+                );
+            }
+        }
+        self
+    }
+
+    /// A number must be within `min..=max`.
+    pub(crate) fn range(
+        &mut self,
+        field: &str,
+        value: Option<i32>,
+        min: i32,
+        max: i32,
+    ) -> &mut Self {
+        if let Some(value) = value {
+            if !(min..=max).contains(&value) {
+                self.violation(
+                    field,
+                    format!("must be between {min} and {max}, got {value}"),
+                );
+            }
+        }
+        self
+    }
+
+    /// `from` must not be after `to`, see `ArchiveDate::is_after`.
+    pub(crate) fn not_after(
+        &mut self,
+        (from_field, from): (&str, Option<&ArchiveDate>),
+        (to_field, to): (&str, Option<&ArchiveDate>),
+    ) -> &mut Self {
+        if let (Some(from), Some(to)) = (from, to) {
+            if from.is_after(to) {
+                self.violation(
+                    from_field,
+                    format!("must not be after {to_field}, got {from} > {to}"),
+                );
+            }
+        }
+        self
+    }
+
+    /// Adds the violations of a check of `field`, e.g. `Timeframe::check`.
+    pub(crate) fn check(&mut self, field: &str, result: Option<ApiResult<()>>) -> &mut Self {
+        if let Some(Err(e)) = result {
+            self.violations.extend(violations_of(field, e));
+        }
+        self
+    }
+
+    /// Adds violations found earlier, e.g. by the setters of a params builder.
+    pub(crate) fn extend(&mut self, violations: Vec<Violation>) -> &mut Self {
+        self.violations.extend(violations);
+        self
+    }
+
+    /// Adds the violations of another validation, keeping their fields.
+    pub(crate) fn merge(&mut self, result: ApiResult<()>) -> &mut Self {
+        match result {
+            Ok(()) => {}
+            Err(Error::InvalidParameter { violations, .. }) if !violations.is_empty() => {
+                self.violations.extend(violations)
+            }
+            Err(e) => self.violations.extend(violations_of("params", e)),
+        }
+        self
+    }
+
+    /// Returns an `Error::InvalidParameter` listing all violations, if any.
+    pub(crate) fn finish(&mut self) -> ApiResult<()> {
+        let violations = std::mem::take(&mut self.violations);
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::from_violations(violations))
+        }
+    }
+}
+
+/// Turns an error raised while checking or parsing the value of `field` into violations of `field`.
+pub(crate) fn violations_of(field: &str, error: Error) -> Vec<Violation> {
+    match error {
+        Error::InvalidParameter { violations, .. } if !violations.is_empty() => violations
+            .into_iter()
+            .map(|violation| Violation {
+                field: field.to_string(),
+                message: violation.message,
+            })
+            .collect(),
+        e => vec![Violation {
+            field: field.to_string(),
+            message: e.to_string(),
+        }],
+    }
+}
@@ -0,0 +1,398 @@
+#[cfg(feature = "async")]
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use std::{
+    ops::Deref,
+    sync::{mpsc, Arc, Condvar, Mutex},
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+#[cfg(feature = "async")]
+use futures_core::Stream;
+
+use super::dedup::{DedupKey, Deduplicator, LruStore};
+#[cfg(feature = "async")]
+use super::pagination::PageFuture;
+use super::pagination::Paginated;
+use crate::{ApiResult, Article, NewsResponse};
+
+/// Default delay between two polls of a watcher.
+pub const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+/// Default max number of pages fetched by a poll of a watcher.
+pub const DEFAULT_MAX_PAGES_PER_POLL: usize = 5;
+/// Default number of article ids remembered by a watcher.
+pub const DEFAULT_SEEN_CAPACITY: usize = 10_000;
+
+/// Handle stopping a watcher, from any thread.
+///
+/// A stopped watcher finishes the poll in progress, if any, and does not start another one.
+#[derive(Debug, Clone, Default)]
+pub struct Shutdown {
+    inner: Arc<ShutdownInner>,
+}
+
+#[derive(Debug, Default)]
+struct ShutdownInner {
+    stopped: Mutex<bool>,
+    condvar: Condvar,
+    #[cfg(feature = "async")]
+    waker: Mutex<Option<Waker>>,
+}
+
+impl Shutdown {
+    /// Stops the watcher.
+    pub fn shutdown(&self) {
+        *self.inner.stopped.lock().unwrap() = true;
+        self.inner.condvar.notify_all();
+        #[cfg(feature = "async")]
+        if let Some(waker) = self.inner.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+
+    /// Whether the watcher was stopped.
+    pub fn is_shutdown(&self) -> bool {
+        *self.inner.stopped.lock().unwrap()
+    }
+
+    /// Waits for `timeout`, returning early with `true` if the watcher is stopped meanwhile.
+    fn wait(&self, timeout: Duration) -> bool {
+        let stopped = self.inner.stopped.lock().unwrap();
+        let (stopped, _) = self
+            .inner
+            .condvar
+            .wait_timeout_while(stopped, timeout, |stopped| !*stopped)
+            .unwrap();
+        *stopped
+    }
+
+    /// Wakes `waker` when the watcher is stopped.
+    #[cfg(feature = "async")]
+    fn register(&self, waker: &Waker) {
+        *self.inner.waker.lock().unwrap() = Some(waker.clone());
+    }
+}
+
+/// Polling progress shared by the blocking and async watchers.
+struct WatchState<P> {
+    params: P,
+    interval: Duration,
+    max_pages: usize,
+    seen: Deduplicator<LruStore>,
+    polls: usize,
+    pages: usize,
+    shutdown: Shutdown,
+}
+
+impl<P: Paginated> WatchState<P> {
+    fn new(params: &P) -> Self {
+        Self {
+            params: params.clone(),
+            interval: DEFAULT_WATCH_INTERVAL,
+            max_pages: DEFAULT_MAX_PAGES_PER_POLL,
+            seen: Deduplicator::bounded(DEFAULT_SEEN_CAPACITY).keys([DedupKey::ArticleId]),
+            polls: 0,
+            pages: 0,
+            shutdown: Shutdown::default(),
+        }
+    }
+
+    /// Returns the params of the first page of a new poll.
+    fn start_poll(&mut self) -> P {
+        self.pages = 0;
+        let mut params = self.params.clone();
+        params.set_page(None);
+        params
+    }
+
+    /// Keeps the new articles of a fetched page, and returns the params of the next page
+    /// unless an already seen article was reached.
+    ///
+    /// The first poll fetches a single page, since no article was seen yet.
+    fn process(&mut self, page: NewsResponse, params: &mut P) -> (Vec<Article>, Option<P>) {
+        self.pages += 1;
+        let count = page.results.len();
+        let articles: Vec<Article> = page
+            .results
+            .into_iter()
+            .filter(|article| self.seen.is_new(article))
+            .collect();
+        let reached_seen = articles.len() < count;
+        let next = match page.next_page {
+            Some(next_page)
+                if !reached_seen && count > 0 && self.polls > 0 && self.pages < self.max_pages =>
+            {
+                params.set_page(Some(next_page));
+                Some(params.clone())
+            }
+            _ => {
+                self.polls += 1;
+                None
+            }
+        };
+        (articles, next)
+    }
+
+    /// Ends a poll stopped by an error.
+    fn fail_poll(&mut self) {
+        self.polls += 1;
+    }
+}
+
+/// Polls a paginated endpoint at a fixed interval and returns only the articles not seen before,
+/// see `LatestNews::watch_latest` and `CryptoNews::watch_crypto_news`.
+///
+/// Each poll pages until it reaches an already seen article, `max_pages_per_poll` pages or the last page.
+/// Errors are returned and the watcher carries on with the next poll.
+pub struct Watcher<C, P> {
+    client: C,
+    fetch: fn(&C, &P) -> ApiResult<NewsResponse>,
+    state: WatchState<P>,
+}
+
+impl<C, P: Paginated> Watcher<C, P> {
+    pub(crate) fn new(client: C, params: &P, fetch: fn(&C, &P) -> ApiResult<NewsResponse>) -> Self {
+        Self {
+            client,
+            fetch,
+            state: WatchState::new(params),
+        }
+    }
+
+    /// Sets the delay between two polls, 5 minutes by default.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.state.interval = interval;
+        self
+    }
+
+    /// Sets the max number of pages fetched by a poll, 5 by default.
+    pub fn max_pages_per_poll(mut self, max_pages: usize) -> Self {
+        self.state.max_pages = max_pages.max(1);
+        self
+    }
+
+    /// Sets the deduplicator telling the seen articles apart,
+    /// by default the last 10,000 article ids.
+    pub fn deduplicator(mut self, deduplicator: Deduplicator<LruStore>) -> Self {
+        self.state.seen = deduplicator;
+        self
+    }
+
+    /// Returns a handle stopping the watcher.
+    pub fn shutdown_handle(&self) -> Shutdown {
+        self.state.shutdown.clone()
+    }
+
+    /// Polls once, returning the new articles, followed by the error which stopped the poll, if any.
+    pub fn poll(&mut self) -> Vec<ApiResult<Article>> {
+        let mut params = self.state.start_poll();
+        let mut results = Vec::new();
+        loop {
+            match (self.fetch)(&self.client, &params) {
+                Ok(page) => {
+                    let (articles, next) = self.state.process(page, &mut params);
+                    results.extend(articles.into_iter().map(Ok));
+                    match next {
+                        Some(next) => params = next,
+                        None => return results,
+                    }
+                }
+                Err(e) => {
+                    self.state.fail_poll();
+                    results.push(Err(e));
+                    return results;
+                }
+            }
+        }
+    }
+
+    /// Polls until stopped by the `shutdown_handle`, calling `on_article` for each new article or error.
+    pub fn run(&mut self, mut on_article: impl FnMut(ApiResult<Article>)) {
+        while !self.state.shutdown.is_shutdown() {
+            self.poll().into_iter().for_each(&mut on_article);
+            if self.state.shutdown.wait(self.state.interval) {
+                break;
+            }
+        }
+    }
+
+    /// Runs the watcher on a new thread, sending the new articles and errors to the returned receiver.
+    ///
+    /// The thread ends when stopped by the `shutdown_handle` or when the receiver is dropped,
+    /// without waiting for new articles.
+    pub fn spawn(mut self) -> (WatchReceiver, JoinHandle<()>)
+    where
+        C: Send + 'static,
+        P: Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel();
+        let shutdown = self.shutdown_handle();
+        let handle = thread::spawn(move || {
+            let shutdown = self.shutdown_handle();
+            self.run(|article| {
+                if sender.send(article).is_err() {
+                    shutdown.shutdown();
+                }
+            });
+        });
+        (WatchReceiver { receiver, shutdown }, handle)
+    }
+}
+
+/// Receiver of the articles and errors of a spawned `Watcher`, stopping it when dropped.
+///
+/// Derefs to the `mpsc::Receiver` of the channel, e.g. to call `recv` or `iter`.
+#[derive(Debug)]
+pub struct WatchReceiver {
+    receiver: mpsc::Receiver<ApiResult<Article>>,
+    shutdown: Shutdown,
+}
+
+impl Deref for WatchReceiver {
+    type Target = mpsc::Receiver<ApiResult<Article>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.receiver
+    }
+}
+
+impl<'a> IntoIterator for &'a WatchReceiver {
+    type Item = ApiResult<Article>;
+    type IntoIter = mpsc::Iter<'a, ApiResult<Article>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.receiver.iter()
+    }
+}
+
+impl Drop for WatchReceiver {
+    fn drop(&mut self) {
+        self.shutdown.shutdown();
+    }
+}
+
+/// Step of an `AsyncWatcher`.
+#[cfg(feature = "async")]
+enum WatchStep<'a, P> {
+    Idle,
+    Fetching(P, PageFuture<'a>),
+    Sleeping(Pin<Box<tokio::time::Sleep>>),
+}
+
+#[cfg(feature = "async")]
+impl<P> WatchStep<'_, P> {
+    fn sleeping(interval: Duration) -> Self {
+        WatchStep::Sleeping(Box::pin(tokio::time::sleep(interval)))
+    }
+}
+
+/// Async counterpart of `Watcher`, implementing `Stream`.\
+/// Requires the `async` feature.
+///
+/// The stream ends when stopped by the `shutdown_handle`, and never otherwise.
+#[cfg(feature = "async")]
+pub struct AsyncWatcher<'a, P> {
+    fetch: Box<dyn Fn(P) -> PageFuture<'a> + Send + Sync + 'a>,
+    state: WatchState<P>,
+    step: WatchStep<'a, P>,
+    current: std::vec::IntoIter<Article>,
+}
+
+#[cfg(feature = "async")]
+impl<'a, P: Paginated> AsyncWatcher<'a, P> {
+    pub(crate) fn new(params: &P, fetch: impl Fn(P) -> PageFuture<'a> + Send + Sync + 'a) -> Self {
+        Self {
+            fetch: Box::new(fetch),
+            state: WatchState::new(params),
+            step: WatchStep::Idle,
+            current: Vec::new().into_iter(),
+        }
+    }
+
+    /// Sets the delay between two polls, 5 minutes by default.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.state.interval = interval;
+        self
+    }
+
+    /// Sets the max number of pages fetched by a poll, 5 by default.
+    pub fn max_pages_per_poll(mut self, max_pages: usize) -> Self {
+        self.state.max_pages = max_pages.max(1);
+        self
+    }
+
+    /// Sets the deduplicator telling the seen articles apart,
+    /// by default the last 10,000 article ids.
+    pub fn deduplicator(mut self, deduplicator: Deduplicator<LruStore>) -> Self {
+        self.state.seen = deduplicator;
+        self
+    }
+
+    /// Returns a handle stopping the watcher.
+    pub fn shutdown_handle(&self) -> Shutdown {
+        self.state.shutdown.clone()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<P: Paginated + Unpin> Stream for AsyncWatcher<'_, P> {
+    type Item = ApiResult<Article>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(article) = this.current.next() {
+                return Poll::Ready(Some(Ok(article)));
+            }
+            match &mut this.step {
+                WatchStep::Idle => {
+                    if this.state.shutdown.is_shutdown() {
+                        return Poll::Ready(None);
+                    }
+                    let params = this.state.start_poll();
+                    let pending = (this.fetch)(params.clone());
+                    this.step = WatchStep::Fetching(params, pending);
+                }
+                WatchStep::Fetching(params, pending) => {
+                    let result = match pending.as_mut().poll(cx) {
+                        Poll::Ready(result) => result,
+                        Poll::Pending => return Poll::Pending,
+                    };
+                    let interval = this.state.interval;
+                    match result {
+                        Ok(page) => {
+                            let (articles, next) = this.state.process(page, params);
+                            this.current = articles.into_iter();
+                            this.step = match next {
+                                Some(next) => {
+                                    let pending = (this.fetch)(next.clone());
+                                    WatchStep::Fetching(next, pending)
+                                }
+                                None => WatchStep::sleeping(interval),
+                            };
+                        }
+                        Err(e) => {
+                            this.state.fail_poll();
+                            this.step = WatchStep::sleeping(interval);
+                            return Poll::Ready(Some(Err(e)));
+                        }
+                    }
+                }
+                WatchStep::Sleeping(sleep) => {
+                    this.state.shutdown.register(cx.waker());
+                    if this.state.shutdown.is_shutdown() {
+                        return Poll::Ready(None);
+                    }
+                    match sleep.as_mut().poll(cx) {
+                        Poll::Ready(()) => this.step = WatchStep::Idle,
+                        Poll::Pending => return Poll::Pending,
+                    }
+                }
+            }
+        }
+    }
+}
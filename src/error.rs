@@ -0,0 +1,509 @@
+use std::borrow::Cow;
+use std::convert::Infallible;
+use std::error::Error as StdError;
+use std::fmt::{self, Display, Formatter};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::apis::days_from_civil;
+use crate::Json;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The API key is missing, invalid or not allowed to access the resource.\
+    /// HTTP status 401 or 403
+    Unauthorized {
+        status: u16,
+        code: Option<String>,
+        message: String,
+    },
+    /// Too many requests were sent in a short period of time.\
+    /// HTTP status 429
+    RateLimited {
+        status: u16,
+        code: Option<String>,
+        message: String,
+        /// Delay requested by the `Retry-After` header, if any.
+        retry_after: Option<Duration>,
+    },
+    /// The API credits of the plan are exhausted.\
+    /// Error code `ApiLimitExceeded`, with any HTTP status but the ones of `Unauthorized` and `InvalidParameter`
+    QuotaExceeded {
+        status: u16,
+        code: Option<String>,
+        message: String,
+    },
+    /// A parameter of the request was rejected, either by the API or by the validation run before sending it.\
+    /// HTTP status 400, 409, 415 or 422
+    InvalidParameter {
+        /// `None` when raised by the validation before sending the request.
+        status: Option<u16>,
+        code: Option<String>,
+        /// Name of the rejected parameter, if known.\
+        /// From the API, taken from `results.field` or `results.parameter`, else from the first quoted name of the message.
+        field: Option<String>,
+        message: String,
+        /// Every violated constraint found by the validation.
+        violations: Vec<Violation>,
+    },
+    /// Any other error returned by the API.
+    Api {
+        status: u16,
+        code: Option<String>,
+        message: String,
+        /// Delay requested by the `Retry-After` header, if any, e.g. on a 503.
+        retry_after: Option<Duration>,
+    },
+    /// The request could not be sent or the response could not be received.
+    Transport {
+        message: String,
+        source: Option<Box<dyn StdError + Send + Sync>>,
+    },
+    /// The API key could not be loaded, e.g. a missing environment variable or an unreadable file.
+    Auth {
+        message: String,
+        source: Option<Box<dyn StdError + Send + Sync>>,
+    },
+    /// A cached response could not be read or written, or is missing from an offline cache.
+    Cache {
+        /// Cache key of the request, see `cache_key`.
+        key: String,
+        message: String,
+        source: Option<Box<dyn StdError + Send + Sync>>,
+    },
+    /// A cassette could not be read, or has no recorded response for the request.
+    Cassette {
+        /// Path of the cassette file.
+        path: PathBuf,
+        message: String,
+        source: Option<Box<dyn StdError + Send + Sync>>,
+    },
+    /// The response could not be decoded, e.g. an HTML error page of a proxy or a truncated body.
+    Decode {
+        status: u16,
+        /// `Content-Type` header of the response, if any.
+        content_type: Option<String>,
+        /// Beginning of the response body.
+        body: String,
+        message: String,
+        source: serde_json::Error,
+    },
+}
+
+/// Codes of `results.code` returned by the API once the credits of the plan are exhausted.
+const QUOTA_CODES: &[&str] = &["ApiLimitExceeded"];
+
+impl Error {
+    /// Builds the error matching an error response of the API.
+    ///
+    /// The API returns its errors as `{"status": "error", "results": {"message": "...", "code": "..."}}`.
+    pub(crate) fn from_response(status: u16, body: &Json, retry_after: Option<Duration>) -> Self {
+        let results = &body["results"];
+        let code = results["code"].as_str().map(str::to_string);
+        let message = match results["message"].as_str() {
+            Some(message) => message.to_string(),
+            None => body.to_string(),
+        };
+        let quota = code.as_deref().is_some_and(|code| {
+            QUOTA_CODES
+                .iter()
+                .any(|quota| quota.eq_ignore_ascii_case(code))
+        });
+        match status {
+            401 | 403 => Error::Unauthorized {
+                status,
+                code,
+                message,
+            },
+            400 | 409 | 415 | 422 => Error::InvalidParameter {
+                status: Some(status),
+                code,
+                field: rejected_field(results, &message),
+                message,
+                violations: Vec::new(),
+            },
+            _ if quota => Error::QuotaExceeded {
+                status,
+                code,
+                message,
+            },
+            429 => Error::RateLimited {
+                status,
+                code,
+                message,
+                retry_after,
+            },
+            _ => Error::Api {
+                status,
+                code,
+                message,
+                retry_after,
+            },
+        }
+    }
+
+    /// Builds the `InvalidParameter` error of violations found before sending the request.
+    pub(crate) fn from_violations(violations: Vec<Violation>) -> Self {
+        let (field, message) = match violations.as_slice() {
+            [violation] => (Some(violation.field.clone()), violation.message.clone()),
+            _ => (
+                None,
+                violations
+                    .iter()
+                    .map(Violation::to_string)
+                    .collect::<Vec<_>>()
+                    .join("; "),
+            ),
+        };
+        Error::InvalidParameter {
+            status: None,
+            code: None,
+            field,
+            message,
+            violations,
+        }
+    }
+
+    /// Builds the `InvalidParameter` error of a single violation found before sending the request.
+    pub(crate) fn invalid_parameter(field: &str, message: String) -> Self {
+        Self::from_violations(vec![Violation {
+            field: field.to_string(),
+            message,
+        }])
+    }
+
+    /// Replaces `secret`, e.g. the API key, in the messages of the error.\
+    /// The source of a `Transport` or `Cache` error is dropped if it mentions `secret`.
+    pub(crate) fn redact(self, secret: &str) -> Self {
+        let text = |text: String| redact(&text, secret).into_owned();
+        let source = |source: Option<Box<dyn StdError + Send + Sync>>| {
+            source.filter(|source| secret.is_empty() || !source.to_string().contains(secret))
+        };
+        match self {
+            Error::Unauthorized {
+                status,
+                code,
+                message,
+            } => Error::Unauthorized {
+                status,
+                code,
+                message: text(message),
+            },
+            Error::RateLimited {
+                status,
+                code,
+                message,
+                retry_after,
+            } => Error::RateLimited {
+                status,
+                code,
+                message: text(message),
+                retry_after,
+            },
+            Error::QuotaExceeded {
+                status,
+                code,
+                message,
+            } => Error::QuotaExceeded {
+                status,
+                code,
+                message: text(message),
+            },
+            Error::InvalidParameter {
+                status,
+                code,
+                field,
+                message,
+                violations,
+            } => Error::InvalidParameter {
+                status,
+                code,
+                field,
+                message: text(message),
+                violations,
+            },
+            Error::Api {
+                status,
+                code,
+                message,
+                retry_after,
+            } => Error::Api {
+                status,
+                code,
+                message: text(message),
+                retry_after,
+            },
+            Error::Transport { message, source: s } => Error::Transport {
+                message: text(message),
+                source: source(s),
+            },
+            Error::Auth { message, source: s } => Error::Auth {
+                message: text(message),
+                source: source(s),
+            },
+            Error::Cache {
+                key,
+                message,
+                source: s,
+            } => Error::Cache {
+                key,
+                message: text(message),
+                source: source(s),
+            },
+            Error::Cassette {
+                path,
+                message,
+                source: s,
+            } => Error::Cassette {
+                path,
+                message: text(message),
+                source: source(s),
+            },
+            Error::Decode {
+                status,
+                content_type,
+                body,
+                message,
+                source,
+            } => Error::Decode {
+                status,
+                content_type,
+                body: text(body),
+                message: text(message),
+                source,
+            },
+        }
+    }
+
+    /// HTTP status of the response which caused the error, if any.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Error::Unauthorized { status, .. }
+            | Error::RateLimited { status, .. }
+            | Error::QuotaExceeded { status, .. }
+            | Error::Api { status, .. }
+            | Error::Decode { status, .. } => Some(*status),
+            Error::InvalidParameter { status, .. } => *status,
+            Error::Transport { .. }
+            | Error::Auth { .. }
+            | Error::Cache { .. }
+            | Error::Cassette { .. } => None,
+        }
+    }
+
+    /// Error code returned by the API in `results.code`, if any.
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            Error::Unauthorized { code, .. }
+            | Error::RateLimited { code, .. }
+            | Error::QuotaExceeded { code, .. }
+            | Error::InvalidParameter { code, .. }
+            | Error::Api { code, .. } => code.as_deref(),
+            Error::Transport { .. }
+            | Error::Auth { .. }
+            | Error::Cache { .. }
+            | Error::Cassette { .. }
+            | Error::Decode { .. } => None,
+        }
+    }
+
+    /// Delay requested by the API before retrying, if any.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Error::RateLimited { retry_after, .. } | Error::Api { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Unauthorized {
+                status, message, ..
+            } => write!(f, "Unauthorized ({status}): {message}"),
+            Error::RateLimited {
+                status, message, ..
+            } => write!(f, "Rate limited ({status}): {message}"),
+            Error::QuotaExceeded {
+                status, message, ..
+            } => write!(f, "Quota exceeded ({status}): {message}"),
+            Error::InvalidParameter {
+                status,
+                field,
+                message,
+                ..
+            } => {
+                write!(f, "Invalid parameter")?;
+                if let Some(field) = field {
+                    write!(f, " `{field}`")?;
+                }
+                if let Some(status) = status {
+                    write!(f, " ({status})")?;
+                }
+                write!(f, ": {message}")
+            }
+            Error::Api {
+                status, message, ..
+            } => write!(f, "API error ({status}): {message}"),
+            Error::Transport { message, .. } => write!(f, "Transport error: {message}"),
+            Error::Auth { message, .. } => write!(f, "Auth error: {message}"),
+            Error::Cache { key, message, .. } => write!(f, "Cache error ({key}): {message}"),
+            Error::Cassette { path, message, .. } => {
+                write!(f, "Cassette error ({}): {message}", path.display())
+            }
+            Error::Decode {
+                status,
+                content_type,
+                body,
+                message,
+                ..
+            } => write!(
+                f,
+                "Decode error ({status}, content-type: {}): {message}, body: {body}",
+                content_type.as_deref().unwrap_or("none")
+            ),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Error::Transport {
+                source: Some(source),
+                ..
+            }
+            | Error::Auth {
+                source: Some(source),
+                ..
+            }
+            | Error::Cache {
+                source: Some(source),
+                ..
+            }
+            | Error::Cassette {
+                source: Some(source),
+                ..
+            } => Some(source.as_ref()),
+            Error::Decode { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(infallible: Infallible) -> Self {
+        match infallible {}
+    }
+}
+
+/// A violated constraint of a request parameter, found before sending the request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    /// Name of the parameter, e.g. `q_in_title`.
+    pub field: String,
+    /// Description of the violated constraint.
+    pub message: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Name of the parameter rejected by the API, e.g. `category` in "The parameter 'category' is not supported".
+fn rejected_field(results: &Json, message: &str) -> Option<String> {
+    if let Some(field) = ["field", "parameter"]
+        .iter()
+        .find_map(|name| results[name].as_str())
+    {
+        return Some(field.to_string());
+    }
+    let is_name = |name: &str| {
+        !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    };
+    ['\'', '`', '"'].iter().find_map(|&quote| {
+        message
+            .split(quote)
+            .skip(1)
+            .step_by(2)
+            .find(|name| is_name(name))
+            .map(str::to_string)
+    })
+}
+
+/// Value shown in place of a secret.
+pub(crate) const REDACTED: &str = "[REDACTED]";
+
+/// Replaces `secret` in `text`.
+pub(crate) fn redact<'a>(text: &'a str, secret: &str) -> Cow<'a, str> {
+    if secret.is_empty() || !text.contains(secret) {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(text.replace(secret, REDACTED))
+    }
+}
+
+/// Parses the `Retry-After` header, given either in seconds or as an HTTP date, see RFC 9110 section 10.2.3.\
+/// A date in the past is no delay.
+pub(crate) fn parse_retry_after(value: Option<&str>) -> Option<Duration> {
+    let value = value?.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = parse_http_date(value)?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+    Some(Duration::from_secs(date.saturating_sub(now)))
+}
+
+/// Parses an HTTP date into Unix seconds, either as an IMF-fixdate, e.g. `Sun, 06 Nov 1994 08:49:37 GMT`,
+/// or in the obsolete RFC 850 and asctime forms.
+fn parse_http_date(value: &str) -> Option<u64> {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let mut month = None;
+    let mut time = None;
+    let mut numbers = Vec::new();
+    // The day of the week and the `GMT` zone are skipped
+    for token in value
+        .split([' ', ',', '-'])
+        .filter(|token| !token.is_empty())
+    {
+        if let Some(index) = MONTHS
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(token))
+        {
+            month = Some(index as u8 + 1);
+        } else if token.contains(':') {
+            time = Some(token);
+        } else if let Ok(number) = token.parse::<u32>() {
+            numbers.push((number, token.len()));
+        }
+    }
+    // The day comes before the year in every form
+    let [(day, _), (year, year_digits)] = numbers[..] else {
+        return None;
+    };
+    let year = match year {
+        // Two-digit years of RFC 850 dates
+        0..=69 if year_digits == 2 => year + 2000,
+        70..=99 if year_digits == 2 => year + 1900,
+        _ => year,
+    };
+    let mut parts = time?.split(':').map(|part| part.parse::<u64>().ok());
+    let (hours, minutes, seconds) = (parts.next()??, parts.next()??, parts.next()??);
+    if parts.next().is_some()
+        || !(1..=31).contains(&day)
+        || hours > 23
+        || minutes > 59
+        || seconds > 60
+    {
+        return None;
+    }
+    let days = days_from_civil(i32::try_from(year).ok()?, month?, day as u8);
+    let days = u64::try_from(days).ok()?;
+    Some(days * 24 * 60 * 60 + hours * 60 * 60 + minutes * 60 + seconds)
+}
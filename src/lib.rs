@@ -1,26 +1,36 @@
-use std::fmt::{self, Display, Formatter};
-use ureq::serde_json;
+#[cfg(not(any(feature = "blocking", feature = "async")))]
+compile_error!("at least one of the `blocking` or `async` features must be enabled");
 
 pub mod apis;
+mod error;
+pub mod models;
 pub mod newsdata_io;
-pub use newsdata_io::NewsdataIO;
+pub use apis::{
+    ArchiveDate, ArchiveRange, Articles, Category, Country, CryptoNews, DedupKey, Deduplicator,
+    Flag, GetCryptoNewsParams, GetCryptoNewsParamsBuilder, GetLatestNewsParams,
+    GetLatestNewsParamsBuilder, GetNewsArchiveParams, GetNewsArchiveParamsBuilder,
+    GetNewsSourcesParams, GetNewsSourcesParamsBuilder, IntoValues, Language, LatestNews, LruStore,
+    NewsArchive, NewsSources, Pages, Paginated, PriorityDomain, RangeProgress, SeenStore,
+    Sentiment, Timeframe, ToQuery, Validate, WatchReceiver, Watcher, Window,
+};
+#[cfg(feature = "async")]
+pub use apis::{
+    AsyncArchiveRange, AsyncArticles, AsyncCryptoNews, AsyncLatestNews, AsyncNewsArchive,
+    AsyncNewsSources, AsyncPages, AsyncWatcher,
+};
+pub use error::{Error, Violation};
+pub use models::{Article, NewsResponse, Source, SourcesResponse};
+pub use newsdata_io::{
+    ApiKey, Auth, AuthMode, Cache, CachePolicy, Cassette, CassetteMode, FileCache, HttpRequest,
+    HttpResponse, KeyProvider, MemoryCache, Middleware, MockTransport, NewsdataIOBuilder,
+    RateLimit, RateLimiter, RetryPolicy, Transport,
+};
+#[cfg(feature = "async")]
+pub use newsdata_io::{
+    AsyncNewsdataIO, AsyncRequests, AsyncTransport, ReqwestTransport, TransportFuture,
+};
+#[cfg(feature = "blocking")]
+pub use newsdata_io::{NewsdataIO, Requests, UreqTransport};
 
 pub type Json = serde_json::Value;
 pub type ApiResult<T> = Result<T, Error>;
-
-#[derive(Debug)]
-pub enum Error {
-    /// An Error returned by the API
-    ApiError(String),
-    /// An Error not related to the API
-    RequestError(String),
-}
-
-impl Display for Error {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        match self {
-            Error::ApiError(msg) => write!(f, "API error: {}", msg),
-            Error::RequestError(msg) => write!(f, "Request error: {}", msg),
-        }
-    }
-}
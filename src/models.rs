@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::Json;
+
+/// A news article returned by the latest, crypto and archive endpoints.
+///
+/// Fields which are only populated on some plans (e.g. `ai_tag`, `sentiment_stats`)
+/// are kept as raw `Json`, since the API returns a placeholder string instead of
+/// the actual value on lower tier plans.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Article {
+    /// Unique identifier of the news article.
+    pub article_id: String,
+    /// Title of the news article.
+    #[serde(default)]
+    pub title: Option<String>,
+    /// Link to the original news article.
+    #[serde(default)]
+    pub link: Option<String>,
+    /// Keywords of the news article.
+    #[serde(default)]
+    pub keywords: Option<Vec<String>>,
+    /// Authors of the news article.
+    #[serde(default)]
+    pub creator: Option<Vec<String>>,
+    /// Link to the video of the news article.
+    #[serde(default)]
+    pub video_url: Option<String>,
+    /// Short description of the news article.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Full content of the news article.
+    #[serde(default)]
+    pub content: Option<String>,
+    /// Publish date of the news article.\
+    /// Format: YYYY-MM-DD HH:MM:SS
+    #[serde(default, rename = "pubDate")]
+    pub pub_date: Option<String>,
+    /// Timezone of `pub_date`.
+    #[serde(default, rename = "pubDateTZ")]
+    pub pub_date_tz: Option<String>,
+    /// Link to the image of the news article.
+    #[serde(default)]
+    pub image_url: Option<String>,
+    /// Identifier of the news source.
+    #[serde(default)]
+    pub source_id: Option<String>,
+    /// Priority of the news source. Lower is more important.
+    #[serde(default)]
+    pub source_priority: Option<u64>,
+    /// Name of the news source.
+    #[serde(default)]
+    pub source_name: Option<String>,
+    /// Url of the news source.
+    #[serde(default)]
+    pub source_url: Option<String>,
+    /// Icon of the news source.
+    #[serde(default)]
+    pub source_icon: Option<String>,
+    /// Language of the news article.
+    #[serde(default)]
+    pub language: Option<String>,
+    /// Countries of the news article.
+    #[serde(default)]
+    pub country: Option<Vec<String>>,
+    /// Categories of the news article.
+    #[serde(default)]
+    pub category: Option<Vec<String>>,
+    /// Coins mentioned in the news article.\
+    /// Only returned by the crypto endpoint.
+    #[serde(default)]
+    pub coin: Option<Vec<String>>,
+    /// AI generated tags of the news article.
+    #[serde(default)]
+    pub ai_tag: Option<Json>,
+    /// Sentiment of the news article.
+    #[serde(default)]
+    pub sentiment: Option<String>,
+    /// Sentiment statistics of the news article.
+    #[serde(default)]
+    pub sentiment_stats: Option<Json>,
+    /// AI generated regions of the news article.
+    #[serde(default)]
+    pub ai_region: Option<Json>,
+    /// AI generated organizations of the news article.
+    #[serde(default)]
+    pub ai_org: Option<Json>,
+    /// Whether the news article is a duplicate of another one.
+    #[serde(default)]
+    pub duplicate: Option<bool>,
+    /// Fields returned by the API which are not covered above.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Json>,
+}
+
+/// Response of the latest, crypto and archive endpoints.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct NewsResponse {
+    /// Status of the response, e.g. "success".
+    pub status: String,
+    /// Total number of articles matching the request.
+    #[serde(default, rename = "totalResults")]
+    pub total_results: u64,
+    /// Articles of the current page.
+    #[serde(default)]
+    pub results: Vec<Article>,
+    /// page parameter for the next page, `None` on the last page.\
+    /// [Detail](https://newsdata.io/documentation/#pagination)
+    #[serde(default, rename = "nextPage")]
+    pub next_page: Option<String>,
+    /// Fields returned by the API which are not covered above.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Json>,
+}
+
+/// A news source returned by the sources endpoint.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Source {
+    /// Unique identifier of the news source.
+    pub id: String,
+    /// Name of the news source.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Url of the news source.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Icon of the news source.
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// Priority of the news source. Lower is more important.
+    #[serde(default)]
+    pub priority: Option<u64>,
+    /// Description of the news source.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Categories of the news source.
+    #[serde(default)]
+    pub category: Option<Vec<String>>,
+    /// Languages of the news source.
+    #[serde(default)]
+    pub language: Option<Vec<String>>,
+    /// Countries of the news source.
+    #[serde(default)]
+    pub country: Option<Vec<String>>,
+    /// Last time the news source was fetched.
+    #[serde(default)]
+    pub last_fetch: Option<String>,
+    /// Fields returned by the API which are not covered above.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Json>,
+}
+
+/// Response of the sources endpoint.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct SourcesResponse {
+    /// Status of the response, e.g. "success".
+    pub status: String,
+    /// Total number of sources matching the request.
+    #[serde(default, rename = "totalResults")]
+    pub total_results: u64,
+    /// Sources matching the request.
+    #[serde(default)]
+    pub results: Vec<Source>,
+    /// Fields returned by the API which are not covered above.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Json>,
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use super::cache::ResponseCache;
+use super::cassette::Cassette;
+use super::middleware::Middlewares;
+use super::transport::AsyncTransport;
+use super::{Auth, AuthMode, NewsdataIOBuilder, RateLimiter, RetryPolicy};
+
+/// Async client of the Newsdata.io API, built on reqwest by default.\
+/// Requires the `async` feature.
+pub struct AsyncNewsdataIO {
+    pub auth: Auth,
+    pub(crate) auth_mode: AuthMode,
+    pub(crate) transport: Arc<dyn AsyncTransport>,
+    pub(crate) base_url: String,
+    pub(crate) api_version: String,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) rate_limiter: Option<RateLimiter>,
+    pub(crate) cache: Option<ResponseCache>,
+    pub(crate) cassette: Option<Cassette>,
+    pub(crate) middlewares: Middlewares,
+}
+
+impl Clone for AsyncNewsdataIO {
+    fn clone(&self) -> Self {
+        Self {
+            auth: self.auth.clone(),
+            auth_mode: self.auth_mode,
+            transport: self.transport.clone(),
+            base_url: self.base_url.clone(),
+            api_version: self.api_version.clone(),
+            retry_policy: self.retry_policy.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+            cache: self.cache.clone(),
+            cassette: self.cassette.clone(),
+            middlewares: self.middlewares.clone(),
+        }
+    }
+}
+
+impl AsyncNewsdataIO {
+    pub fn new(auth: Auth) -> Self {
+        NewsdataIOBuilder::new(auth).build_async()
+    }
+
+    /// Returns a builder to configure the client, e.g. to target a local mock server.
+    pub fn builder(auth: Auth) -> NewsdataIOBuilder {
+        NewsdataIOBuilder::new(auth)
+    }
+
+    /// Returns the full URL of an endpoint, e.g. `https://newsdata.io/api/1/latest`.
+    pub fn endpoint_url(&self, sub_url: &str) -> String {
+        if self.api_version.is_empty() {
+            format!("{}/{}", self.base_url, sub_url)
+        } else {
+            format!("{}/{}/{}", self.base_url, self.api_version, sub_url)
+        }
+    }
+}
@@ -1,21 +1,202 @@
-use serde::{Deserialize, Serialize};
+use std::env::{self, VarError};
+use std::fmt::{self, Debug, Formatter};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
 
-#[derive(Debug, Serialize, Deserialize)]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::Zeroizing;
+
+use crate::error::REDACTED;
+use crate::{ApiResult, Error};
+
+/// Header carrying the API key in `AuthMode::Header`.
+pub const API_KEY_HEADER: &str = "X-ACCESS-KEY";
+
+/// How the API key is sent to the API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AuthMode {
+    /// In the `X-ACCESS-KEY` header, keeping the key out of URLs and proxy logs.
+    #[default]
+    Header,
+    /// In the `apikey` query parameter.
+    Query,
+}
+
+/// An API key, redacted from `Debug` and zeroed from memory when dropped.
+#[derive(Clone)]
+pub struct ApiKey(Zeroizing<String>);
+
+impl ApiKey {
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self(Zeroizing::new(api_key.into()))
+    }
+
+    /// Returns the key itself, e.g. to send it.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Debug for ApiKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ApiKey").field(&REDACTED).finish()
+    }
+}
+
+impl From<String> for ApiKey {
+    fn from(api_key: String) -> Self {
+        Self::new(api_key)
+    }
+}
+
+impl From<&str> for ApiKey {
+    fn from(api_key: &str) -> Self {
+        Self::new(api_key)
+    }
+}
+
+/// Source of the API key fetched before each attempt of a request, e.g. a secret manager
+/// rotating the key.
+///
+/// Implemented for closures returning an `ApiResult<ApiKey>`.
+pub trait KeyProvider: Send + Sync {
+    fn api_key(&self) -> ApiResult<ApiKey>;
+}
+
+impl<F> KeyProvider for F
+where
+    F: Fn() -> ApiResult<ApiKey> + Send + Sync,
+{
+    fn api_key(&self) -> ApiResult<ApiKey> {
+        self()
+    }
+}
+
+/// Credentials of the client.
+///
+/// The key is redacted from `Debug` and `Serialize`, and zeroed from memory when dropped.
+#[derive(Clone)]
 pub struct Auth {
-    api_key: String,
+    source: KeySource,
 }
-impl Clone for Auth {
-    fn clone(&self) -> Self {
+
+#[derive(Clone)]
+enum KeySource {
+    Static(ApiKey),
+    Provider(Arc<dyn KeyProvider>),
+}
+
+impl Auth {
+    pub fn new(api_key: String) -> Self {
         Self {
-            api_key: self.api_key.clone(),
+            source: KeySource::Static(ApiKey::new(api_key)),
+        }
+    }
+
+    /// Reads the API key from the environment variable `name`, e.g. `NEWSDATA_API_KEY`.
+    ///
+    /// # Returns
+    ///
+    /// An `Error::Auth` if the variable is not set, not valid unicode or empty.
+    pub fn from_env(name: &str) -> ApiResult<Self> {
+        // The message of `VarError::NotUnicode` would show the value
+        let api_key = env::var(name).map(Zeroizing::new).map_err(|e| {
+            let message = match e {
+                VarError::NotPresent => "is not set",
+                VarError::NotUnicode(_) => "is not valid unicode",
+            };
+            Error::Auth {
+                message: format!("The environment variable `{name}` {message}"),
+                source: None,
+            }
+        })?;
+        Self::from_content(&format!("environment variable `{name}`"), &api_key)
+    }
+
+    /// Reads the API key from the file at `path`, ignoring the surrounding whitespace,
+    /// e.g. a mounted secret.
+    ///
+    /// # Returns
+    ///
+    /// An `Error::Auth` if the file cannot be read, with the io error as source, or is empty.
+    pub fn from_file(path: impl AsRef<Path>) -> ApiResult<Self> {
+        let path = path.as_ref();
+        let api_key = fs::read_to_string(path)
+            .map(Zeroizing::new)
+            .map_err(|e| Error::Auth {
+                message: format!("Failed to read the API key from {}: {e}", path.display()),
+                source: Some(Box::new(e)),
+            })?;
+        Self::from_content(&format!("file {}", path.display()), &api_key)
+    }
+
+    /// Fetches the API key from `provider` before each attempt of a request.
+    pub fn from_provider(provider: impl KeyProvider + 'static) -> Self {
+        Self {
+            source: KeySource::Provider(Arc::new(provider)),
+        }
+    }
+
+    fn from_content(origin: &str, content: &str) -> ApiResult<Self> {
+        match content.trim() {
+            "" => Err(Error::Auth {
+                message: format!("The API key of the {origin} is empty"),
+                source: None,
+            }),
+            api_key => Ok(Self::new(api_key.to_string())),
+        }
+    }
+
+    /// Returns the API key, fetched from the provider if any.
+    pub fn api_key(&self) -> ApiResult<ApiKey> {
+        match &self.source {
+            KeySource::Static(api_key) => Ok(api_key.clone()),
+            KeySource::Provider(provider) => provider.api_key(),
         }
     }
+
+    #[deprecated(note = "Copies the key into an unprotected `String`, use `api_key` instead")]
+    pub fn get_api_key(&self) -> ApiResult<String> {
+        self.api_key().map(|api_key| api_key.expose().to_string())
+    }
 }
-impl Auth {
-    pub fn new(api_key: String) -> Self {
-        Self { api_key }
+
+impl Debug for Auth {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            KeySource::Static(api_key) => f.debug_struct("Auth").field("api_key", api_key).finish(),
+            KeySource::Provider(_) => f.debug_struct("Auth").finish_non_exhaustive(),
+        }
+    }
+}
+
+/// Serializes as `{"api_key": "[REDACTED]"}`, so that a serialized configuration never holds the key.
+impl Serialize for Auth {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Redacted {
+            api_key: &'static str,
+        }
+        Redacted { api_key: REDACTED }.serialize(serializer)
     }
-    pub fn get_api_key(&self) -> String {
-        self.api_key.clone()
+}
+
+/// Fails on an empty key, and on the `[REDACTED]` placeholder written by `Serialize`,
+/// which would only be rejected by the API later on.
+impl<'de> Deserialize<'de> for Auth {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Plain {
+            api_key: String,
+        }
+        let api_key = Zeroizing::new(Plain::deserialize(deserializer)?.api_key);
+        match api_key.trim() {
+            "" => Err(de::Error::custom("The API key is empty")),
+            REDACTED => Err(de::Error::custom(
+                "The API key is redacted, set the real key or load it with `Auth::from_env` or `Auth::from_file`",
+            )),
+            api_key => Ok(Self::new(api_key.to_string())),
+        }
     }
 }
@@ -0,0 +1,249 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Debug, Formatter};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::{ApiResult, Json};
+
+/// Storage of the cached responses, e.g. `MemoryCache`.
+///
+/// Implement it to keep the responses on disk or in an external store shared by several services.
+pub trait Cache: Send + Sync {
+    /// Returns the response stored under `key`, if any and not expired.
+    fn get(&self, key: &str) -> ApiResult<Option<Json>>;
+
+    /// Stores `response` under `key` for `ttl`.
+    fn set(&self, key: &str, response: &Json, ttl: Duration) -> ApiResult<()>;
+
+    /// Whether the cache answers every request, failing on a miss instead of letting the request be sent,
+    /// e.g. an offline `FileCache`.\
+    /// An offline cache is asked even for the endpoints with a zero TTL.
+    fn is_offline(&self) -> bool {
+        false
+    }
+}
+
+/// Time to live of the cached responses of each endpoint.
+///
+/// Default: 24 hours for `sources`, 1 hour for `archive` and 1 minute for the other endpoints.\
+/// A zero TTL disables caching for the endpoint, unless the cache is offline.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachePolicy {
+    pub default_ttl: Duration,
+    pub ttls: HashMap<String, Duration>,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self {
+            default_ttl: Duration::from_secs(60),
+            ttls: HashMap::from([
+                ("sources".to_string(), Duration::from_secs(24 * 60 * 60)),
+                ("archive".to_string(), Duration::from_secs(60 * 60)),
+            ]),
+        }
+    }
+}
+
+impl CachePolicy {
+    /// Sets the TTL of the responses of `sub_url`, e.g. `latest`.
+    pub fn ttl(mut self, sub_url: impl Into<String>, ttl: Duration) -> Self {
+        self.ttls.insert(sub_url.into(), ttl);
+        self
+    }
+
+    /// Sets the TTL of the endpoints without a TTL of their own.
+    pub fn default_ttl(mut self, ttl: Duration) -> Self {
+        self.default_ttl = ttl;
+        self
+    }
+
+    /// TTL of the responses of `sub_url`.
+    pub fn ttl_of(&self, sub_url: &str) -> Duration {
+        self.ttls.get(sub_url).copied().unwrap_or(self.default_ttl)
+    }
+}
+
+/// Returns the cache key of a request: the URL of the endpoint followed by its query sorted by name,
+/// each name and value percent-encoded, e.g. `https://newsdata.io/api/1/latest?country=us&q=rust`.\
+/// The `apikey` parameter is left out.
+///
+/// The URL keeps apart the responses of different servers or API versions sharing a store.
+pub fn cache_key(endpoint_url: &str, query_params: Option<&HashMap<String, String>>) -> String {
+    let mut query: Vec<(&String, &String)> = query_params
+        .into_iter()
+        .flatten()
+        .filter(|(key, _)| key.as_str() != "apikey")
+        .collect();
+    query.sort();
+    let query: Vec<String> = query
+        .into_iter()
+        .map(|(key, value)| format!("{}={}", percent_encode(key), percent_encode(value)))
+        .collect();
+    if query.is_empty() {
+        endpoint_url.to_string()
+    } else {
+        format!("{endpoint_url}?{}", query.join("&"))
+    }
+}
+
+/// Percent-encodes every byte of `value` but the unreserved characters of RFC 3986.
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// The cache of a client: its storage and policy.
+#[derive(Clone)]
+pub(crate) struct ResponseCache {
+    pub(crate) store: Arc<dyn Cache>,
+    pub(crate) policy: CachePolicy,
+}
+
+impl Debug for ResponseCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResponseCache")
+            .field("policy", &self.policy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ResponseCache {
+    pub(crate) fn new(store: impl Cache + 'static, policy: CachePolicy) -> Self {
+        Self {
+            store: Arc::new(store),
+            policy,
+        }
+    }
+
+    /// Returns the cached response of a request to `sub_url`, if any, see `cache_key`.
+    pub(crate) fn get(&self, sub_url: &str, key: &str) -> ApiResult<Option<Json>> {
+        if self.policy.ttl_of(sub_url).is_zero() && !self.store.is_offline() {
+            return Ok(None);
+        }
+        self.store.get(key)
+    }
+
+    /// Caches the response of a request to `sub_url`.
+    pub(crate) fn set(&self, sub_url: &str, key: &str, response: &Json) -> ApiResult<()> {
+        let ttl = self.policy.ttl_of(sub_url);
+        if ttl.is_zero() {
+            return Ok(());
+        }
+        self.store.set(key, response, ttl)
+    }
+}
+
+/// In-memory cache, evicting the least recently used responses beyond its capacity.\
+/// Clones share the same responses.
+#[derive(Debug, Clone)]
+pub struct MemoryCache {
+    capacity: usize,
+    entries: Arc<Mutex<MemoryEntries>>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryEntries {
+    tick: u64,
+    entries: HashMap<String, MemoryEntry>,
+    order: BTreeMap<u64, String>,
+}
+
+#[derive(Debug)]
+struct MemoryEntry {
+    response: Json,
+    /// `None` when the TTL is too long to be represented.
+    expires_at: Option<Instant>,
+    tick: u64,
+}
+
+impl MemoryCache {
+    /// Creates a cache holding at most `capacity` responses.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: Arc::new(Mutex::new(MemoryEntries::default())),
+        }
+    }
+
+    /// Number of cached responses, including the expired ones not evicted yet.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes every cached response.
+    pub fn clear(&self) {
+        *self.entries.lock().unwrap() = MemoryEntries::default();
+    }
+}
+
+impl MemoryEntries {
+    /// Marks `key` as the most recently used.
+    fn touch(&mut self, key: &str) {
+        self.tick += 1;
+        if let Some(entry) = self.entries.get_mut(key) {
+            self.order.remove(&entry.tick);
+            entry.tick = self.tick;
+            self.order.insert(self.tick, key.to_string());
+        }
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.order.remove(&entry.tick);
+        }
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get(&self, key: &str) -> ApiResult<Option<Json>> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.entries.get(key) {
+            Some(entry) if entry.expires_at.is_none_or(|at| at > Instant::now()) => {
+                let response = entry.response.clone();
+                entries.touch(key);
+                Ok(Some(response))
+            }
+            Some(_) => {
+                entries.remove(key);
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn set(&self, key: &str, response: &Json, ttl: Duration) -> ApiResult<()> {
+        let mut entries = self.entries.lock().unwrap();
+        entries.remove(key);
+        entries.entries.insert(
+            key.to_string(),
+            MemoryEntry {
+                response: response.clone(),
+                expires_at: Instant::now().checked_add(ttl),
+                tick: 0,
+            },
+        );
+        entries.touch(key);
+        while entries.entries.len() > self.capacity {
+            match entries.order.pop_first() {
+                Some((_, oldest)) => {
+                    entries.entries.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use super::transport::{HttpRequest, HttpResponse};
+use crate::{ApiResult, Error};
+
+/// Whether a `Cassette` records the exchanges with the API or replays them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Sends the requests and appends each request/response pair to the cassette file.
+    Record,
+    /// Serves the responses of the cassette file, without sending any request.
+    Replay,
+}
+
+/// Request/response pairs recorded to a JSON file and replayed from it,
+/// to test code built on the client without an API key.
+///
+/// The API key is never recorded: the headers and the `apikey` parameter are left out,
+/// so a cassette recorded with either `AuthMode` replays with the other.\
+/// On replay, a request is answered by the first unused pair with the same URL and query, ignoring `apikey`,
+/// or by the last matching one once all were used, and fails with `Error::Cassette` if none matches.\
+/// Clones share the same interactions.
+#[derive(Debug, Clone)]
+pub struct Cassette {
+    path: PathBuf,
+    mode: CassetteMode,
+    state: Arc<Mutex<CassetteState>>,
+}
+
+#[derive(Debug, Default)]
+struct CassetteState {
+    interactions: Vec<Interaction>,
+    /// Whether each interaction was already replayed.
+    used: Vec<bool>,
+}
+
+/// Content of a cassette file.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+/// A recorded request/response pair.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: HttpResponse,
+}
+
+/// A request, without its API key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: String,
+    url: String,
+    query: BTreeMap<String, String>,
+}
+
+impl RecordedRequest {
+    fn new(request: &HttpRequest) -> Self {
+        Self {
+            method: "GET".to_string(),
+            url: request.url.clone(),
+            query: request
+                .query
+                .iter()
+                .filter(|(key, _)| key != "apikey")
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl Cassette {
+    /// Creates a cassette recording to `path`, replacing the file if it exists.
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            mode: CassetteMode::Record,
+            state: Arc::default(),
+        }
+    }
+
+    /// Loads the cassette recorded at `path` to replay it.
+    pub fn replay(path: impl Into<PathBuf>) -> ApiResult<Self> {
+        let path = path.into();
+        let content =
+            fs::read_to_string(&path).map_err(|e| cassette_error(&path, "Failed to read", e))?;
+        let mut file: CassetteFile = serde_json::from_str(&content)
+            .map_err(|e| cassette_error(&path, "Failed to parse", e))?;
+        // Cassettes recorded by older versions hold a redacted `apikey` parameter
+        for interaction in &mut file.interactions {
+            interaction.request.query.remove("apikey");
+        }
+        Ok(Self {
+            path,
+            mode: CassetteMode::Replay,
+            state: Arc::new(Mutex::new(CassetteState {
+                used: vec![false; file.interactions.len()],
+                interactions: file.interactions,
+            })),
+        })
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        self.mode
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Number of recorded interactions.
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().interactions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the recorded response of a request.
+    pub(crate) fn replay_response(&self, request: &HttpRequest) -> ApiResult<HttpResponse> {
+        let request = RecordedRequest::new(request);
+        let mut state = self.state.lock().unwrap();
+        let matching: Vec<usize> = (0..state.interactions.len())
+            .filter(|index| state.interactions[*index].request == request)
+            .collect();
+        let index = matching
+            .iter()
+            .find(|index| !state.used[**index])
+            .or(matching.last())
+            .copied()
+            .ok_or_else(|| Error::Cassette {
+                path: self.path.clone(),
+                message: format!(
+                    "No recorded interaction for GET {} {:?}",
+                    request.url, request.query
+                ),
+                source: None,
+            })?;
+        state.used[index] = true;
+        Ok(state.interactions[index].response.clone())
+    }
+
+    /// Records the response of a request and saves the cassette file.
+    pub(crate) fn record_response(
+        &self,
+        request: &HttpRequest,
+        response: &HttpResponse,
+    ) -> ApiResult<()> {
+        let mut state = self.state.lock().unwrap();
+        state.interactions.push(Interaction {
+            request: RecordedRequest::new(request),
+            response: response.clone(),
+        });
+        state.used.push(true);
+        let file = CassetteFile {
+            interactions: state.interactions.clone(),
+        };
+        let content = serde_json::to_string_pretty(&file)
+            .map_err(|e| cassette_error(&self.path, "Failed to encode", e))?;
+        self.path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| fs::write(&self.path, content))
+            .map_err(|e| cassette_error(&self.path, "Failed to write", e))
+    }
+}
+
+fn cassette_error(
+    path: &Path,
+    action: &str,
+    source: impl std::error::Error + Send + Sync + 'static,
+) -> Error {
+    Error::Cassette {
+        path: path.to_path_buf(),
+        message: format!("{action} the cassette: {source}"),
+        source: Some(Box::new(source)),
+    }
+}
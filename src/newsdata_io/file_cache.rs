@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use super::cache::{cache_key, Cache};
+use crate::{ApiResult, Error, Json};
+
+/// Cache keeping each response as a JSON file, e.g. to replay real responses during development
+/// without spending credits.
+///
+/// The response of a key is stored in `<dir>/<hh>/<hash>.json`, where `hash` is the FNV-1a hash of the key
+/// and `hh` its first two characters.\
+/// In offline mode the cache is read-only, serves expired responses and fails with `Error::Cache`
+/// on every miss instead of sending the request, whatever the TTL of the endpoint.
+#[derive(Debug, Clone)]
+pub struct FileCache {
+    dir: PathBuf,
+    offline: bool,
+}
+
+/// Content of a cache file.
+#[derive(Debug, Serialize, Deserialize)]
+struct FileEntry {
+    key: String,
+    /// Unix time in seconds after which the response is expired, `None` for seeded responses.
+    expires_at: Option<u64>,
+    response: Json,
+}
+
+impl FileCache {
+    /// Creates a cache storing its files under `dir`, created when the first response is written.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            offline: false,
+        }
+    }
+
+    /// Enables the offline mode, see `FileCache`.
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Path of the file of `key`.
+    pub fn path_of(&self, key: &str) -> PathBuf {
+        let hash = format!("{:016x}", fnv1a(key.as_bytes()));
+        self.dir.join(&hash[..2]).join(format!("{hash}.json"))
+    }
+
+    /// Stores a response which never expires, e.g. a fixture.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint_url`: The URL of the endpoint, e.g. `https://newsdata.io/api/1/latest`,
+    ///   see `NewsdataIO::endpoint_url`.
+    /// * `query_params`: The query of the request, e.g. `params.to_query()`.
+    /// * `response`: The response of the API.
+    pub fn seed(
+        &self,
+        endpoint_url: &str,
+        query_params: Option<&HashMap<String, String>>,
+        response: &Json,
+    ) -> ApiResult<()> {
+        self.write(&cache_key(endpoint_url, query_params), response, None)
+    }
+
+    /// Same as `seed`, reading the response from the JSON file at `path`.
+    pub fn seed_from_file(
+        &self,
+        endpoint_url: &str,
+        query_params: Option<&HashMap<String, String>>,
+        path: impl AsRef<Path>,
+    ) -> ApiResult<()> {
+        let key = cache_key(endpoint_url, query_params);
+        let path = path.as_ref();
+        let content = fs::read_to_string(path)
+            .map_err(|e| cache_error(&key, format!("Failed to read {}", path.display()), e))?;
+        let response: Json = serde_json::from_str(&content)
+            .map_err(|e| cache_error(&key, format!("Failed to parse {}", path.display()), e))?;
+        self.write(&key, &response, None)
+    }
+
+    fn write(&self, key: &str, response: &Json, expires_at: Option<u64>) -> ApiResult<()> {
+        let path = self.path_of(key);
+        let entry = FileEntry {
+            key: key.to_string(),
+            expires_at,
+            response: response.clone(),
+        };
+        let content = serde_json::to_string_pretty(&entry)
+            .map_err(|e| cache_error(key, "Failed to encode the response".to_string(), e))?;
+        // Write to a temporary file first, so that readers never see a partial file
+        let tmp = path.with_extension("json.tmp");
+        path.parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| fs::write(&tmp, content))
+            .and_then(|()| fs::rename(&tmp, &path))
+            .map_err(|e| cache_error(key, format!("Failed to write {}", path.display()), e))
+    }
+}
+
+impl Cache for FileCache {
+    fn get(&self, key: &str) -> ApiResult<Option<Json>> {
+        let path = self.path_of(key);
+        let miss = || {
+            if self.offline {
+                Err(Error::Cache {
+                    key: key.to_string(),
+                    message: format!("Not found in the offline cache {}", self.dir.display()),
+                    source: None,
+                })
+            } else {
+                Ok(None)
+            }
+        };
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return miss(),
+            Err(e) => {
+                return Err(cache_error(
+                    key,
+                    format!("Failed to read {}", path.display()),
+                    e,
+                ));
+            }
+        };
+        let entry: FileEntry = serde_json::from_str(&content)
+            .map_err(|e| cache_error(key, format!("Failed to parse {}", path.display()), e))?;
+        let expired = entry
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= unix_now());
+        if entry.key != key {
+            // Another key with the same hash
+            return miss();
+        }
+        if expired && !self.offline {
+            return Ok(None);
+        }
+        Ok(Some(entry.response))
+    }
+
+    fn set(&self, key: &str, response: &Json, ttl: Duration) -> ApiResult<()> {
+        if self.offline {
+            return Ok(());
+        }
+        self.write(
+            key,
+            response,
+            Some(unix_now().saturating_add(ttl.as_secs())),
+        )
+    }
+
+    fn is_offline(&self) -> bool {
+        self.offline
+    }
+}
+
+fn cache_error(
+    key: &str,
+    message: String,
+    source: impl std::error::Error + Send + Sync + 'static,
+) -> Error {
+    Error::Cache {
+        key: key.to_string(),
+        message: format!("{message}: {source}"),
+        source: Some(Box::new(source)),
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |now| now.as_secs())
+}
+
+/// 64 bits FNV-1a hash, stable across platforms and Rust versions unlike `DefaultHasher`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
@@ -0,0 +1,72 @@
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
+
+use super::transport::{HttpRequest, HttpResponse};
+use crate::ApiResult;
+
+/// Hooks around each attempt of a request, e.g. to log, collect metrics, add headers or serve responses
+/// from a custom cache.
+///
+/// The middlewares of a client are called in the order they were added before the request is sent,
+/// and in reverse order once the response or error is received.\
+/// Responses served by the cache of the client skip the middlewares.
+pub trait Middleware: Send + Sync {
+    /// Inspects or modifies the request before it is sent.
+    ///
+    /// Returning a response, or an error, skips the transport and the next middlewares:
+    /// it is handed to the `on_response` of this middleware and of the previous ones.
+    fn on_request(&self, request: &mut HttpRequest) -> Option<ApiResult<HttpResponse>> {
+        let _ = request;
+        None
+    }
+
+    /// Inspects or modifies the response, or the failure to get one, before it is parsed.
+    fn on_response(&self, request: &HttpRequest, response: &mut ApiResult<HttpResponse>) {
+        let _ = (request, response);
+    }
+}
+
+/// The ordered middlewares of a client.
+#[derive(Clone, Default)]
+pub(crate) struct Middlewares(Vec<Arc<dyn Middleware>>);
+
+impl Debug for Middlewares {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Middlewares")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
+
+impl Middlewares {
+    pub(crate) fn push(&mut self, middleware: impl Middleware + 'static) {
+        self.0.push(Arc::new(middleware));
+    }
+
+    /// Runs the `on_request` hooks, and returns the number of middlewares called
+    /// with the response of the one which answered, if any.
+    pub(crate) fn before(
+        &self,
+        request: &mut HttpRequest,
+    ) -> (usize, Option<ApiResult<HttpResponse>>) {
+        for (index, middleware) in self.0.iter().enumerate() {
+            if let Some(response) = middleware.on_request(request) {
+                return (index + 1, Some(response));
+            }
+        }
+        (self.0.len(), None)
+    }
+
+    /// Runs the `on_response` hooks of the first `called` middlewares, in reverse order.
+    pub(crate) fn after(
+        &self,
+        called: usize,
+        request: &HttpRequest,
+        mut response: ApiResult<HttpResponse>,
+    ) -> ApiResult<HttpResponse> {
+        for middleware in self.0[..called].iter().rev() {
+            middleware.on_response(request, &mut response);
+        }
+        response
+    }
+}
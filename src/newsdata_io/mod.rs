@@ -1,7 +1,40 @@
+#[cfg(feature = "async")]
+mod async_newsdata_io;
 mod auth;
+mod builder;
+mod cache;
+mod cassette;
+mod file_cache;
+mod middleware;
+#[cfg(feature = "blocking")]
+#[allow(clippy::module_inception)]
 mod newsdata_io;
+mod rate_limit;
 mod requests;
+mod retry;
+mod transport;
 
-pub use auth::Auth;
+#[cfg(feature = "async")]
+pub use async_newsdata_io::AsyncNewsdataIO;
+pub use auth::{ApiKey, Auth, AuthMode, KeyProvider, API_KEY_HEADER};
+pub use builder::{NewsdataIOBuilder, DEFAULT_API_VERSION, DEFAULT_BASE_URL};
+pub use cache::{cache_key, Cache, CachePolicy, MemoryCache};
+pub use cassette::{Cassette, CassetteMode};
+pub use file_cache::FileCache;
+pub use middleware::Middleware;
+#[cfg(feature = "blocking")]
 pub use newsdata_io::NewsdataIO;
+pub use rate_limit::{RateLimit, RateLimiter};
+#[cfg(feature = "async")]
+pub use requests::AsyncRequests;
+#[cfg(feature = "blocking")]
 pub use requests::Requests;
+pub use retry::RetryPolicy;
+#[cfg(feature = "blocking")]
+pub use transport::UreqTransport;
+#[cfg(feature = "async")]
+pub use transport::{AsyncTransport, ReqwestTransport, TransportFuture};
+pub use transport::{
+    HttpRequest, HttpResponse, MockTransport, Transport, DEFAULT_CONNECT_TIMEOUT,
+    DEFAULT_READ_TIMEOUT, DEFAULT_USER_AGENT,
+};
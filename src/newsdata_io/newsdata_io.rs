@@ -1,26 +1,57 @@
-use ureq::{Agent, AgentBuilder};
+use std::sync::Arc;
 
-use super::Auth;
+use super::cache::ResponseCache;
+use super::cassette::Cassette;
+use super::middleware::Middlewares;
+use super::transport::Transport;
+use super::{Auth, AuthMode, NewsdataIOBuilder, RateLimiter, RetryPolicy};
 
 pub struct NewsdataIO {
     pub auth: Auth,
-    pub(crate) agent: Agent,
+    pub(crate) auth_mode: AuthMode,
+    pub(crate) transport: Arc<dyn Transport>,
+    pub(crate) base_url: String,
+    pub(crate) api_version: String,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) rate_limiter: Option<RateLimiter>,
+    pub(crate) cache: Option<ResponseCache>,
+    pub(crate) cassette: Option<Cassette>,
+    pub(crate) middlewares: Middlewares,
 }
 
 impl Clone for NewsdataIO {
     fn clone(&self) -> Self {
         Self {
             auth: self.auth.clone(),
-            agent: self.agent.clone(),
+            auth_mode: self.auth_mode,
+            transport: self.transport.clone(),
+            base_url: self.base_url.clone(),
+            api_version: self.api_version.clone(),
+            retry_policy: self.retry_policy.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+            cache: self.cache.clone(),
+            cassette: self.cassette.clone(),
+            middlewares: self.middlewares.clone(),
         }
     }
 }
 
 impl NewsdataIO {
     pub fn new(auth: Auth) -> Self {
-        Self {
-            auth,
-            agent: AgentBuilder::new().build(),
+        NewsdataIOBuilder::new(auth).build()
+    }
+
+    /// Returns a builder to configure the client, e.g. to target a local mock server.
+    pub fn builder(auth: Auth) -> NewsdataIOBuilder {
+        NewsdataIOBuilder::new(auth)
+    }
+
+    /// Returns the full URL of an endpoint, e.g. `https://newsdata.io/api/1/latest`.
+    pub fn endpoint_url(&self, sub_url: &str) -> String {
+        if self.api_version.is_empty() {
+            format!("{}/{}", self.base_url, sub_url)
+        } else {
+            format!("{}/{}/{}", self.base_url, self.api_version, sub_url)
         }
     }
 }
@@ -0,0 +1,113 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// A limit of `requests` per `window`, allowing bursts of up to `burst` requests.
+///
+/// e.g. `RateLimit::new(30, Duration::from_secs(15 * 60))` for 30 requests per 15 minutes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Number of requests allowed per `window`.
+    pub requests: u32,
+    /// Length of the window.
+    pub window: Duration,
+    /// Max number of requests which can be sent at once.
+    pub burst: u32,
+}
+
+impl RateLimit {
+    /// Creates a new `RateLimit` whose burst is the whole window, i.e. `requests`.
+    pub fn new(requests: u32, window: Duration) -> Self {
+        Self {
+            requests,
+            window,
+            burst: requests,
+        }
+    }
+
+    /// Sets the max number of requests which can be sent at once.
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst;
+        self
+    }
+
+    /// Number of tokens regained per second.
+    fn rate(&self) -> f64 {
+        self.requests as f64 / self.window.as_secs_f64()
+    }
+}
+
+/// Token bucket of a single `RateLimit`.
+#[derive(Debug)]
+struct Bucket {
+    limit: RateLimit,
+    /// Available tokens, negative when requests are already queued.
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            tokens: limit.burst.max(1) as f64,
+            updated_at: Instant::now(),
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.updated_at).as_secs_f64();
+        self.tokens =
+            (self.tokens + elapsed * self.limit.rate()).min(self.limit.burst.max(1) as f64);
+        self.updated_at = now;
+    }
+
+    /// Time until a token is available.
+    fn wait(&self) -> Duration {
+        if self.tokens >= 1.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64((1.0 - self.tokens) / self.limit.rate())
+        }
+    }
+}
+
+/// Client side token bucket rate limiter, enforcing one or more `RateLimit`s.
+///
+/// Clones share the same buckets, so every clone of a client counts against the same limits.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    buckets: Arc<Mutex<Vec<Bucket>>>,
+}
+
+impl RateLimiter {
+    /// Creates a new `RateLimiter` enforcing all of `limits`.
+    pub fn new(limits: impl IntoIterator<Item = RateLimit>) -> Self {
+        Self {
+            buckets: Arc::new(Mutex::new(
+                limits
+                    .into_iter()
+                    .filter(|limit| limit.requests > 0 && !limit.window.is_zero())
+                    .map(Bucket::new)
+                    .collect(),
+            )),
+        }
+    }
+
+    /// Reserves a slot for a request, and returns how long to wait before sending it.
+    pub fn reserve(&self) -> Duration {
+        let now = Instant::now();
+        let mut buckets = self
+            .buckets
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut wait = Duration::ZERO;
+        for bucket in buckets.iter_mut() {
+            bucket.refill(now);
+            wait = wait.max(bucket.wait());
+        }
+        for bucket in buckets.iter_mut() {
+            bucket.tokens -= 1.0;
+        }
+        wait
+    }
+}
@@ -1,51 +1,406 @@
 use std::collections::HashMap;
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "blocking")]
+use std::thread;
+use std::time::Duration;
 
+use super::auth::{AuthMode, API_KEY_HEADER};
+use super::cache::{cache_key, ResponseCache};
+use super::cassette::{Cassette, CassetteMode};
+use super::transport::{HttpRequest, HttpResponse};
+#[cfg(feature = "async")]
+use super::AsyncNewsdataIO;
+#[cfg(feature = "blocking")]
 use super::NewsdataIO;
+use crate::error::{parse_retry_after, redact};
 use crate::Error;
 use crate::{ApiResult, Json};
 
-use log::{debug, error};
+use log::{debug, error, warn};
+use serde::de::DeserializeOwned;
 
+#[cfg(feature = "blocking")]
 pub trait Requests {
     fn get(&self, sub_url: &str, query_params: Option<HashMap<String, String>>) -> ApiResult<Json>;
+
+    /// Same as `get`, but deserializes the response into `T`.
+    fn get_as<T: DeserializeOwned>(
+        &self,
+        sub_url: &str,
+        query_params: Option<HashMap<String, String>>,
+    ) -> ApiResult<T> {
+        decode(ApiResponse::ok(self.get(sub_url, query_params)?), sub_url)
+    }
 }
 
+#[cfg(feature = "blocking")]
 impl Requests for NewsdataIO {
     fn get(&self, sub_url: &str, query_params: Option<HashMap<String, String>>) -> ApiResult<Json> {
-        let mut request = self
-            .agent
-            .get(&(format!("https://newsdata.io/api/1/{}", sub_url)))
-            .query("apikey", self.auth.get_api_key().as_str());
-        match query_params {
-            Some(params) => {
-                for (key, value) in params {
-                    request = request.query(key.as_str(), value.as_str());
+        self.fetch(sub_url, query_params.as_ref())
+            .map(|response| response.json)
+    }
+
+    fn get_as<T: DeserializeOwned>(
+        &self,
+        sub_url: &str,
+        query_params: Option<HashMap<String, String>>,
+    ) -> ApiResult<T> {
+        decode(self.fetch(sub_url, query_params.as_ref())?, sub_url)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl NewsdataIO {
+    /// Sends the request, or serves it from the cache, retrying the failed attempts.
+    fn fetch(
+        &self,
+        sub_url: &str,
+        query_params: Option<&HashMap<String, String>>,
+    ) -> ApiResult<ApiResponse> {
+        let key = cache_key(&self.endpoint_url(sub_url), query_params);
+        if let Some(response) = cached(self.cache.as_ref(), sub_url, &key)? {
+            return Ok(response);
+        }
+        let mut attempt = 1;
+        loop {
+            if let Some(rate_limiter) = &self.rate_limiter {
+                let wait = rate_limiter.reserve();
+                if !wait.is_zero() {
+                    debug!("==> ⏳\n\tRate limited api: {sub_url}, wait: {wait:?}");
+                    thread::sleep(wait);
                 }
             }
-            None => {}
+            match self.call(sub_url, query_params) {
+                Err(e) => match self.retry_policy.delay(attempt, &e) {
+                    Some(delay) => {
+                        warn!("<== 🔁\n\tRetry api: {sub_url}, attempt: {attempt}, delay: {delay:?}, error: {e}");
+                        thread::sleep(delay);
+                        attempt += 1;
+                    }
+                    None => return Err(e),
+                },
+                Ok(response) => {
+                    cache(self.cache.as_ref(), sub_url, &key, &response.json);
+                    return Ok(response);
+                }
+            }
+        }
+    }
+
+    /// Makes a single attempt of the request.
+    fn call(
+        &self,
+        sub_url: &str,
+        query_params: Option<&HashMap<String, String>>,
+    ) -> ApiResult<ApiResponse> {
+        let api_key = self.auth.api_key()?;
+        let api_key = api_key.expose();
+        let mut request = http_request(
+            self.endpoint_url(sub_url),
+            api_key,
+            self.auth_mode,
+            query_params,
+        );
+        let (called, response) = self.middlewares.before(&mut request);
+        let response = match (response, &self.cassette) {
+            (Some(response), _) => response,
+            (None, Some(cassette)) if cassette.mode() == CassetteMode::Replay => {
+                cassette.replay_response(&request)
+            }
+            (None, cassette) => self
+                .transport
+                .send(&request)
+                .inspect(|response| record(cassette.as_ref(), &request, response, sub_url)),
         };
-        let response = request.set("Content-Type", "application/json").call();
-        deal_response(response, sub_url)
+        let response = self.middlewares.after(called, &request, response);
+        deal_http_response(response, sub_url, api_key)
+    }
+}
+
+/// Async counterpart of `Requests`.
+#[cfg(feature = "async")]
+pub trait AsyncRequests {
+    fn get(
+        &self,
+        sub_url: &str,
+        query_params: Option<HashMap<String, String>>,
+    ) -> impl Future<Output = ApiResult<Json>> + Send;
+
+    /// Same as `get`, but deserializes the response into `T`.
+    fn get_as<T: DeserializeOwned>(
+        &self,
+        sub_url: &str,
+        query_params: Option<HashMap<String, String>>,
+    ) -> impl Future<Output = ApiResult<T>> + Send
+    where
+        Self: Sync,
+    {
+        async move {
+            decode(
+                ApiResponse::ok(self.get(sub_url, query_params).await?),
+                sub_url,
+            )
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncRequests for AsyncNewsdataIO {
+    async fn get(
+        &self,
+        sub_url: &str,
+        query_params: Option<HashMap<String, String>>,
+    ) -> ApiResult<Json> {
+        self.fetch(sub_url, query_params.as_ref())
+            .await
+            .map(|response| response.json)
+    }
+
+    async fn get_as<T: DeserializeOwned>(
+        &self,
+        sub_url: &str,
+        query_params: Option<HashMap<String, String>>,
+    ) -> ApiResult<T> {
+        decode(self.fetch(sub_url, query_params.as_ref()).await?, sub_url)
     }
 }
 
-fn deal_response(response: Result<ureq::Response, ureq::Error>, sub_url: &str) -> ApiResult<Json> {
-    match response {
-        Ok(response) => {
-            let json = response.into_json::<Json>().unwrap();
-            debug!("<== ✔️\n\tDone api: {sub_url}, resp: {json}");
-            Ok(json)
+#[cfg(feature = "async")]
+impl AsyncNewsdataIO {
+    /// Sends the request, or serves it from the cache, retrying the failed attempts.
+    async fn fetch(
+        &self,
+        sub_url: &str,
+        query_params: Option<&HashMap<String, String>>,
+    ) -> ApiResult<ApiResponse> {
+        let key = cache_key(&self.endpoint_url(sub_url), query_params);
+        if let Some(response) = cached(self.cache.as_ref(), sub_url, &key)? {
+            return Ok(response);
         }
-        Err(err) => match err {
-            ureq::Error::Status(status, response) => {
-                let err_msg = response.into_json::<Json>().unwrap();
-                error!("<== ❌\n\tError api: {sub_url}, status: {status}, error: {err_msg}");
-                return Err(Error::ApiError(format!("{err_msg}")));
+        let mut attempt = 1;
+        loop {
+            if let Some(rate_limiter) = &self.rate_limiter {
+                let wait = rate_limiter.reserve();
+                if !wait.is_zero() {
+                    debug!("==> ⏳\n\tRate limited api: {sub_url}, wait: {wait:?}");
+                    tokio::time::sleep(wait).await;
+                }
+            }
+            match self.call(sub_url, query_params).await {
+                Err(e) => match self.retry_policy.delay(attempt, &e) {
+                    Some(delay) => {
+                        warn!("<== 🔁\n\tRetry api: {sub_url}, attempt: {attempt}, delay: {delay:?}, error: {e}");
+                        tokio::time::sleep(delay).await;
+                        attempt += 1;
+                    }
+                    None => return Err(e),
+                },
+                Ok(response) => {
+                    cache(self.cache.as_ref(), sub_url, &key, &response.json);
+                    return Ok(response);
+                }
             }
-            ureq::Error::Transport(e) => {
-                error!("<== ❌\n\tError api: {sub_url}, error: {:?}", e.to_string());
-                return Err(Error::RequestError(e.to_string()));
+        }
+    }
+
+    /// Makes a single attempt of the request.
+    async fn call(
+        &self,
+        sub_url: &str,
+        query_params: Option<&HashMap<String, String>>,
+    ) -> ApiResult<ApiResponse> {
+        let api_key = self.auth.api_key()?;
+        let api_key = api_key.expose();
+        let mut request = http_request(
+            self.endpoint_url(sub_url),
+            api_key,
+            self.auth_mode,
+            query_params,
+        );
+        let (called, response) = self.middlewares.before(&mut request);
+        let response = match (response, &self.cassette) {
+            (Some(response), _) => response,
+            (None, Some(cassette)) if cassette.mode() == CassetteMode::Replay => {
+                cassette.replay_response(&request)
             }
-        },
+            (None, cassette) => self
+                .transport
+                .send(&request)
+                .await
+                .inspect(|response| record(cassette.as_ref(), &request, response, sub_url)),
+        };
+        let response = self.middlewares.after(called, &request, response);
+        deal_http_response(response, sub_url, api_key)
+    }
+}
+
+/// Returns the request of an endpoint, carrying the API key as set by `auth_mode`.
+fn http_request(
+    url: String,
+    api_key: &str,
+    auth_mode: AuthMode,
+    query_params: Option<&HashMap<String, String>>,
+) -> HttpRequest {
+    let mut query = Vec::new();
+    let mut headers = vec![("Content-Type".to_string(), "application/json".to_string())];
+    match auth_mode {
+        AuthMode::Header => headers.push((API_KEY_HEADER.to_string(), api_key.to_string())),
+        AuthMode::Query => query.push(("apikey".to_string(), api_key.to_string())),
+    }
+    if let Some(params) = query_params {
+        query.extend(
+            params
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+    }
+    HttpRequest {
+        url,
+        query,
+        headers,
+    }
+}
+
+/// Records the response of a request in `cassette`, if any, logging the failures.
+///
+/// A failure to record does not fail the request, whose response was already paid for.
+fn record(
+    cassette: Option<&Cassette>,
+    request: &HttpRequest,
+    response: &HttpResponse,
+    sub_url: &str,
+) {
+    if let Some(Err(e)) = cassette.map(|cassette| cassette.record_response(request, response)) {
+        warn!("<== 📼\n\tCassette error api: {sub_url}, error: {e}");
+    }
+}
+
+/// Returns the cached response of a request, if any.
+fn cached(
+    cache: Option<&ResponseCache>,
+    sub_url: &str,
+    key: &str,
+) -> ApiResult<Option<ApiResponse>> {
+    let Some(cache) = cache else {
+        return Ok(None);
+    };
+    let json = cache.get(sub_url, key)?;
+    if let Some(json) = &json {
+        debug!("<== 💾\n\tCached api: {sub_url}, resp: {json}");
+    }
+    Ok(json.map(ApiResponse::ok))
+}
+
+/// Caches a successful response, logging the failures.
+fn cache(cache: Option<&ResponseCache>, sub_url: &str, key: &str, json: &Json) {
+    if let Some(Err(e)) = cache.map(|cache| cache.set(sub_url, key, json)) {
+        warn!("<== 💾\n\tCache error api: {sub_url}, error: {e}");
+    }
+}
+
+/// Max number of characters of the body kept in a `Decode` error.
+const BODY_SNIPPET_LEN: usize = 256;
+
+/// A successful response parsed into `Json`.
+struct ApiResponse {
+    status: u16,
+    /// `Content-Type` header of the response, if any.
+    content_type: Option<String>,
+    json: Json,
+}
+
+impl ApiResponse {
+    /// A response whose status and headers are unknown, e.g. served by the cache.
+    fn ok(json: Json) -> Self {
+        Self {
+            status: 200,
+            content_type: None,
+            json,
+        }
+    }
+}
+
+/// Deserializes the JSON of a successful response into `T`.
+fn decode<T: DeserializeOwned>(response: ApiResponse, sub_url: &str) -> ApiResult<T> {
+    let ApiResponse {
+        status,
+        content_type,
+        json,
+    } = response;
+    T::deserialize(&json).map_err(|e| {
+        error!(
+            "<== ❌\n\tError api: {sub_url}, status: {status}, content-type: {content_type:?}, decode error: {e}"
+        );
+        Error::Decode {
+            status,
+            content_type,
+            body: snippet(&json.to_string()),
+            message: format!("Failed to decode response: {e}"),
+            source: e,
+        }
+    })
+}
+
+/// Parses the body of a response, successful or not.
+fn deal_body(
+    status: u16,
+    content_type: Option<String>,
+    retry_after: Option<Duration>,
+    body: &str,
+    sub_url: &str,
+) -> ApiResult<ApiResponse> {
+    let json = serde_json::from_str::<Json>(body).map_err(|e| {
+        error!(
+            "<== ❌\n\tError api: {sub_url}, status: {status}, content-type: {content_type:?}, decode error: {e}"
+        );
+        Error::Decode {
+            status,
+            content_type: content_type.clone(),
+            body: snippet(body),
+            message: format!("Failed to decode response: {e}"),
+            source: e,
+        }
+    })?;
+    if (200..300).contains(&status) {
+        debug!("<== ✔️\n\tDone api: {sub_url}, resp: {json}");
+        Ok(ApiResponse {
+            status,
+            content_type,
+            json,
+        })
+    } else {
+        error!("<== ❌\n\tError api: {sub_url}, status: {status}, error: {json}");
+        Err(Error::from_response(status, &json, retry_after))
+    }
+}
+
+/// Parses a received or replayed response, logging the failures to get one.
+///
+/// The API key is redacted from the errors and logs, e.g. from a transport error showing the URL.
+fn deal_http_response(
+    response: ApiResult<HttpResponse>,
+    sub_url: &str,
+    api_key: &str,
+) -> ApiResult<ApiResponse> {
+    let response = response.map_err(|e| e.redact(api_key)).inspect_err(|e| {
+        error!("<== ❌\n\tError api: {sub_url}, error: {e}");
+    })?;
+    let content_type = response.header("Content-Type").map(str::to_string);
+    let retry_after = parse_retry_after(response.header("Retry-After"));
+    deal_body(
+        response.status,
+        content_type,
+        retry_after,
+        &redact(&response.body, api_key),
+        sub_url,
+    )
+}
+
+/// Truncates `body` to `BODY_SNIPPET_LEN` characters.
+fn snippet(body: &str) -> String {
+    match body.char_indices().nth(BODY_SNIPPET_LEN) {
+        Some((end, _)) => format!("{}…", &body[..end]),
+        None => body.to_string(),
     }
 }
@@ -0,0 +1,94 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use crate::Error;
+
+/// Policy for retrying requests which failed with a transient error.
+///
+/// Transport errors and responses whose status is in `retryable_statuses` are retried,
+/// waiting `base_delay * 2^(attempt - 1)` between attempts, capped at `max_delay`.
+/// `QuotaExceeded` errors are never retried.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Max number of attempts, including the first one.\
+    /// 1 disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+    /// Upper bound of the delay between two attempts.
+    pub max_delay: Duration,
+    /// Whether to randomize each delay between half and all of its value.
+    pub jitter: bool,
+    /// HTTP statuses which are worth retrying.
+    pub retryable_statuses: Vec<u16>,
+    /// Whether to wait for the `Retry-After` delay of the response, if any, instead of the backoff.\
+    /// A delay longer than `max_delay` is not retried, since an earlier attempt would be rejected again:
+    /// the error is returned with its `retry_after`.
+    pub respect_retry_after: bool,
+}
+
+impl Default for RetryPolicy {
+    /// 3 attempts, from 500ms up to 30s with jitter, on 429 and 5xx gateway errors.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            retryable_statuses: vec![429, 500, 502, 503, 504],
+            respect_retry_after: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy which never retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Whether `error` is worth retrying under this policy.
+    pub fn is_retryable(&self, error: &Error) -> bool {
+        match error {
+            Error::QuotaExceeded { .. } => false,
+            Error::Transport { .. } => true,
+            _ => error
+                .status()
+                .is_some_and(|status| self.retryable_statuses.contains(&status)),
+        }
+    }
+
+    /// Returns the delay to wait before retrying after `attempt` attempts failed with `error`,
+    /// or `None` if the request should not be retried.
+    pub(crate) fn delay(&self, attempt: u32, error: &Error) -> Option<Duration> {
+        if attempt >= self.max_attempts || !self.is_retryable(error) {
+            return None;
+        }
+        if self.respect_retry_after {
+            if let Some(retry_after) = error.retry_after() {
+                return (retry_after <= self.max_delay).then_some(retry_after);
+            }
+        }
+        let exponent = attempt.saturating_sub(1).min(31);
+        let delay = self
+            .base_delay
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay);
+        if self.jitter {
+            let half = delay / 2;
+            let nanos = half.as_nanos().min(u64::MAX as u128) as u64;
+            Some(half + Duration::from_nanos(random() % nanos.saturating_add(1)))
+        } else {
+            Some(delay)
+        }
+    }
+}
+
+/// Returns a random number, seeded by the randomly keyed std hasher.
+fn random() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
@@ -0,0 +1,471 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::{self, Debug, Formatter};
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroize;
+
+use super::auth::API_KEY_HEADER;
+use crate::error::REDACTED;
+use crate::{ApiResult, Error};
+
+/// A GET request to the API, as handed to a transport.
+///
+/// The API key is redacted from `Debug` and zeroed from memory when the request is dropped.
+/// The copies made by the transport, e.g. in the URL it sends, are out of reach and not zeroed.
+#[derive(Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    /// Full URL of the endpoint, without the query.
+    pub url: String,
+    /// Query parameters, the API key included.
+    pub query: Vec<(String, String)>,
+    pub headers: Vec<(String, String)>,
+}
+
+impl HttpRequest {
+    /// Whether the value of the query parameter or header `name` is the API key.
+    pub(crate) fn holds_api_key(name: &str) -> bool {
+        name == "apikey" || name.eq_ignore_ascii_case(API_KEY_HEADER)
+    }
+}
+
+impl Drop for HttpRequest {
+    fn drop(&mut self) {
+        for (name, value) in self.query.iter_mut().chain(self.headers.iter_mut()) {
+            if Self::holds_api_key(name) {
+                value.zeroize();
+            }
+        }
+    }
+}
+
+impl Debug for HttpRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let redacted = |pairs: &[(String, String)]| -> Vec<(String, String)> {
+            pairs
+                .iter()
+                .map(|(name, value)| {
+                    let value = if Self::holds_api_key(name) {
+                        REDACTED
+                    } else {
+                        value
+                    };
+                    (name.clone(), value.to_string())
+                })
+                .collect()
+        };
+        f.debug_struct("HttpRequest")
+            .field("url", &self.url)
+            .field("query", &redacted(&self.query))
+            .field("headers", &redacted(&self.headers))
+            .finish()
+    }
+}
+
+/// A response as received by a transport, before its body is parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpResponse {
+    pub status: u16,
+    /// Headers by lowercase name.
+    pub headers: BTreeMap<String, String>,
+    pub body: String,
+}
+
+impl HttpResponse {
+    /// Creates a response without headers.
+    pub fn new(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: BTreeMap::new(),
+            body: body.into(),
+        }
+    }
+
+    /// Adds a header to the response.
+    pub fn with_header(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.headers.insert(name.to_lowercase(), value.into());
+        self
+    }
+
+    /// Returns the value of a header, whatever the case of `name`.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_lowercase()).map(String::as_str)
+    }
+}
+
+/// Default timeout for establishing a connection.
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// Default timeout for each read of a response.
+pub const DEFAULT_READ_TIMEOUT: Duration = Duration::from_secs(30);
+/// Default `User-Agent` header.
+pub const DEFAULT_USER_AGENT: &str = concat!("newsdata-io-api/", env!("CARGO_PKG_VERSION"));
+
+/// HTTP settings of the default transports, set on `NewsdataIOBuilder`.
+///
+/// The credentials of the proxy URL are redacted from `Debug`.
+#[derive(Clone, PartialEq, Eq)]
+pub(crate) struct HttpOptions {
+    pub(crate) connect_timeout: Option<Duration>,
+    pub(crate) read_timeout: Option<Duration>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) proxy: Option<String>,
+    pub(crate) user_agent: String,
+    pub(crate) max_idle_connections: Option<usize>,
+    /// PEM encoded certificates, trusted on top of the default roots.
+    pub(crate) root_certificates: Vec<Vec<u8>>,
+}
+
+impl Default for HttpOptions {
+    fn default() -> Self {
+        Self {
+            connect_timeout: Some(DEFAULT_CONNECT_TIMEOUT),
+            read_timeout: Some(DEFAULT_READ_TIMEOUT),
+            timeout: None,
+            proxy: None,
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            max_idle_connections: None,
+            root_certificates: Vec::new(),
+        }
+    }
+}
+
+impl Debug for HttpOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpOptions")
+            .field("connect_timeout", &self.connect_timeout)
+            .field("read_timeout", &self.read_timeout)
+            .field("timeout", &self.timeout)
+            .field("proxy", &self.proxy.as_deref().map(redact_userinfo))
+            .field("user_agent", &self.user_agent)
+            .field("max_idle_connections", &self.max_idle_connections)
+            .field("root_certificates", &self.root_certificates)
+            .finish()
+    }
+}
+
+/// Redacts the `user:password@` part of `url`, if any.
+fn redact_userinfo(url: &str) -> String {
+    let start = url.find("://").map_or(0, |index| index + 3);
+    let authority_len = url[start..]
+        .find(['/', '?', '#'])
+        .unwrap_or(url.len() - start);
+    match url[start..start + authority_len].rfind('@') {
+        Some(at) => format!("{}{REDACTED}{}", &url[..start], &url[start + at..]),
+        None => url.to_string(),
+    }
+}
+
+impl HttpOptions {
+    /// Builds the ureq agent of `UreqTransport`.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn ureq_agent(&self) -> ApiResult<ureq::Agent> {
+        let mut builder = ureq::AgentBuilder::new().user_agent(&self.user_agent);
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.timeout_connect(timeout);
+        }
+        if let Some(timeout) = self.read_timeout {
+            builder = builder.timeout_read(timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(proxy) = &self.proxy {
+            let proxy = ureq::Proxy::new(proxy)
+                .map_err(|e| Error::invalid_parameter("proxy", e.to_string()))?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(max) = self.max_idle_connections {
+            builder = builder
+                .max_idle_connections(max)
+                .max_idle_connections_per_host(max);
+        }
+        if !self.root_certificates.is_empty() {
+            builder = builder.tls_config(Arc::new(self.rustls_config()?));
+        }
+        Ok(builder.build())
+    }
+
+    /// TLS configuration trusting the webpki roots and the root certificates.
+    #[cfg(feature = "blocking")]
+    fn rustls_config(&self) -> ApiResult<rustls::ClientConfig> {
+        let invalid =
+            |e: &dyn std::fmt::Display| Error::invalid_parameter("root_certificate", e.to_string());
+        let mut roots = rustls::RootCertStore {
+            roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+        };
+        for pem in &self.root_certificates {
+            let certificates = rustls_pemfile::certs(&mut pem.as_slice())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| invalid(&e))?;
+            if certificates.is_empty() {
+                return Err(invalid(&"No PEM certificate found"));
+            }
+            for certificate in certificates {
+                roots.add(certificate).map_err(|e| invalid(&e))?;
+            }
+        }
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        Ok(rustls::ClientConfig::builder_with_provider(provider)
+            .with_safe_default_protocol_versions()
+            .map_err(|e| invalid(&e))?
+            .with_root_certificates(roots)
+            .with_no_client_auth())
+    }
+
+    /// Builds the reqwest client of `ReqwestTransport`.
+    #[cfg(feature = "async")]
+    pub(crate) fn reqwest_client(&self) -> ApiResult<reqwest::Client> {
+        let mut builder = reqwest::Client::builder().user_agent(&self.user_agent);
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(timeout) = self.read_timeout {
+            builder = builder.read_timeout(timeout);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        if let Some(proxy) = &self.proxy {
+            let proxy = reqwest::Proxy::all(proxy)
+                .map_err(|e| Error::invalid_parameter("proxy", e.to_string()))?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(max) = self.max_idle_connections {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        for pem in &self.root_certificates {
+            let certificates = reqwest::Certificate::from_pem_bundle(pem)
+                .map_err(|e| Error::invalid_parameter("root_certificate", e.to_string()))?;
+            if certificates.is_empty() {
+                return Err(Error::invalid_parameter(
+                    "root_certificate",
+                    "No PEM certificate found".to_string(),
+                ));
+            }
+            for certificate in certificates {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+        builder
+            .build()
+            .map_err(|e| Error::invalid_parameter("http", e.to_string()))
+    }
+}
+
+/// Sends the requests of a `NewsdataIO`, e.g. `UreqTransport` or `MockTransport`.
+///
+/// Implement it to use another HTTP library.
+/// Failures to get a response should be returned as `Error::Transport`, which are retried;
+/// responses with an error status are returned as is.
+pub trait Transport: Send + Sync {
+    fn send(&self, request: &HttpRequest) -> ApiResult<HttpResponse>;
+}
+
+/// The response future of an `AsyncTransport`.
+#[cfg(feature = "async")]
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = ApiResult<HttpResponse>> + Send + 'a>>;
+
+/// Async counterpart of `Transport`, sending the requests of an `AsyncNewsdataIO`,
+/// e.g. `ReqwestTransport` or `MockTransport`.\
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+pub trait AsyncTransport: Send + Sync {
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> TransportFuture<'a>;
+}
+
+/// Default transport of `NewsdataIO`, built on ureq and configured by `NewsdataIOBuilder`.\
+/// Requires the `blocking` feature.
+#[cfg(feature = "blocking")]
+#[derive(Debug, Clone)]
+pub struct UreqTransport {
+    agent: ureq::Agent,
+}
+
+#[cfg(feature = "blocking")]
+impl UreqTransport {
+    pub fn new(agent: ureq::Agent) -> Self {
+        Self { agent }
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Default for UreqTransport {
+    fn default() -> Self {
+        Self::new(
+            HttpOptions::default()
+                .ureq_agent()
+                .expect("the default options are valid"),
+        )
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Transport for UreqTransport {
+    fn send(&self, request: &HttpRequest) -> ApiResult<HttpResponse> {
+        let mut call = self.agent.get(&request.url);
+        for (key, value) in &request.query {
+            call = call.query(key, value);
+        }
+        for (name, value) in &request.headers {
+            call = call.set(name, value);
+        }
+        let response = match call.call() {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(ureq::Error::Transport(e)) => return Err(transport_error(e)),
+        };
+        let status = response.status();
+        let headers = response
+            .headers_names()
+            .into_iter()
+            .filter_map(|name| {
+                let value = response.header(&name)?.to_string();
+                Some((name.to_lowercase(), value))
+            })
+            .collect();
+        let body = response.into_string().map_err(transport_error)?;
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+/// Default transport of `AsyncNewsdataIO`, built on reqwest and configured by `NewsdataIOBuilder`.\
+/// Requires the `async` feature.
+#[cfg(feature = "async")]
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+#[cfg(feature = "async")]
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[cfg(feature = "async")]
+impl Default for ReqwestTransport {
+    fn default() -> Self {
+        Self::new(
+            HttpOptions::default()
+                .reqwest_client()
+                .expect("the default options are valid"),
+        )
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for ReqwestTransport {
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> TransportFuture<'a> {
+        Box::pin(async move {
+            let mut call = self.client.get(&request.url).query(&request.query);
+            for (name, value) in &request.headers {
+                call = call.header(name, value);
+            }
+            let response = call.send().await.map_err(transport_error)?;
+            let status = response.status().as_u16();
+            let headers = response
+                .headers()
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((name.as_str().to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect();
+            let body = response.bytes().await.map_err(transport_error)?;
+            Ok(HttpResponse {
+                status,
+                headers,
+                body: String::from_utf8_lossy(&body).into_owned(),
+            })
+        })
+    }
+}
+
+/// In-memory transport answering with queued responses, in order, and keeping the sent requests,
+/// to test code built on the client without a server.
+///
+/// Fails with `Error::Transport` once the queue is empty.\
+/// Clones share the same queue and requests.
+///
+/// ```
+/// # #[cfg(feature = "blocking")] {
+/// use newsdata_io_api::newsdata_io::{Auth, HttpResponse, MockTransport, NewsdataIO};
+///
+/// let transport = MockTransport::new();
+/// transport.push(HttpResponse::new(200, r#"{"status":"success","results":[]}"#));
+/// let newsdata_io = NewsdataIO::builder(Auth::new("YOUR_API_KEY".to_string()))
+///     .transport(transport.clone())
+///     .build();
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockTransport {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    responses: VecDeque<ApiResult<HttpResponse>>,
+    requests: Vec<HttpRequest>,
+}
+
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a response.
+    pub fn push(&self, response: HttpResponse) {
+        self.state.lock().unwrap().responses.push_back(Ok(response));
+    }
+
+    /// Queues a failure to get a response, e.g. `Error::Transport`.
+    pub fn push_error(&self, error: Error) {
+        self.state.lock().unwrap().responses.push_back(Err(error));
+    }
+
+    /// Returns the requests sent so far.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.state.lock().unwrap().requests.clone()
+    }
+
+    fn answer(&self, request: &HttpRequest) -> ApiResult<HttpResponse> {
+        let mut state = self.state.lock().unwrap();
+        state.requests.push(request.clone());
+        state.responses.pop_front().unwrap_or_else(|| {
+            Err(Error::Transport {
+                message: format!("No response queued for {}", request.url),
+                source: None,
+            })
+        })
+    }
+}
+
+impl Transport for MockTransport {
+    fn send(&self, request: &HttpRequest) -> ApiResult<HttpResponse> {
+        self.answer(request)
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for MockTransport {
+    fn send<'a>(&'a self, request: &'a HttpRequest) -> TransportFuture<'a> {
+        let response = self.answer(request);
+        Box::pin(async move { response })
+    }
+}
+
+fn transport_error(e: impl std::error::Error + Send + Sync + 'static) -> Error {
+    Error::Transport {
+        message: e.to_string(),
+        source: Some(Box::new(e)),
+    }
+}
@@ -0,0 +1,221 @@
+#![cfg(feature = "async")]
+
+use std::future::poll_fn;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+mod common;
+
+use common::{async_client, page, param_requested};
+use futures_core::Stream;
+use newsdata_io_api::apis::{
+    ArchiveDate, AsyncLatestNews, AsyncNewsArchive, GetLatestNewsParams, GetNewsArchiveParams,
+    Window,
+};
+use newsdata_io_api::newsdata_io::{
+    AsyncRequests, AsyncTransport, HttpRequest, HttpResponse, API_KEY_HEADER,
+};
+use newsdata_io_api::{ApiResult, Article, Deduplicator, Error, NewsResponse};
+
+async fn next<St: Stream + Unpin>(stream: &mut St) -> Option<St::Item> {
+    poll_fn(|cx| Pin::new(&mut *stream).poll_next(cx)).await
+}
+
+async fn ids(mut articles: impl Stream<Item = ApiResult<Article>> + Unpin) -> Vec<String> {
+    let mut ids = Vec::new();
+    while let Some(article) = next(&mut articles).await {
+        ids.push(article.unwrap().article_id);
+    }
+    ids
+}
+
+#[tokio::test]
+async fn mock_transport_answers_async_requests_in_order() {
+    let transport = common::mock(vec![HttpResponse::new(200, "{}")]);
+    transport.push_error(Error::Transport {
+        message: "Connection reset".to_string(),
+        source: None,
+    });
+    let request = HttpRequest {
+        url: "https://example.com/api/1/latest".to_string(),
+        query: vec![("q".to_string(), "rust".to_string())],
+        headers: Vec::new(),
+    };
+
+    let response = AsyncTransport::send(&transport, &request).await.unwrap();
+    assert_eq!((response.status, response.body.as_str()), (200, "{}"));
+    assert!(matches!(
+        AsyncTransport::send(&transport, &request).await,
+        Err(Error::Transport { .. })
+    ));
+    // Once the queued responses are used up, requests fail
+    assert!(AsyncTransport::send(&transport, &request).await.is_err());
+    assert_eq!(transport.requests().len(), 3);
+    assert_eq!(transport.requests()[0].query, request.query);
+}
+
+#[tokio::test]
+async fn async_requests_send_the_key_and_decode_the_response() {
+    let (newsdata_io, transport) = async_client(vec![
+        page(&["a"], None),
+        page(&["b"], None),
+        HttpResponse::new(
+            401,
+            r#"{"status":"error","results":{"message":"Invalid API key","code":"Unauthorized"}}"#,
+        ),
+    ]);
+
+    let json = newsdata_io.get("latest", None).await.unwrap();
+    assert_eq!(json["results"][0]["article_id"], "a");
+    let response: NewsResponse = newsdata_io.get_as("latest", None).await.unwrap();
+    assert_eq!(response.results[0].article_id, "b");
+    assert!(matches!(
+        newsdata_io
+            .get_latest(&GetLatestNewsParams::default())
+            .await,
+        Err(Error::Unauthorized { .. })
+    ));
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 3);
+    assert!(requests[0].url.ends_with("/latest"));
+    assert!(requests.iter().all(|request| request
+        .headers
+        .iter()
+        .any(|(name, value)| name == API_KEY_HEADER && value == "test-key")));
+}
+
+#[tokio::test]
+async fn async_pages_stop_at_the_limits() {
+    let (newsdata_io, transport) = async_client(vec![
+        page(&["a", "b"], Some("2")),
+        page(&["c", "d"], Some("3")),
+        page(&["e"], None),
+    ]);
+    let mut pages = newsdata_io
+        .get_latest_pages(&GetLatestNewsParams::default())
+        .max_pages(2);
+    let mut sizes = Vec::new();
+    while let Some(page) = next(&mut pages).await {
+        sizes.push(page.unwrap().results.len());
+    }
+    assert_eq!(sizes, [2, 2]);
+    assert_eq!(
+        param_requested(&transport, "page"),
+        [None, Some("2".to_string())]
+    );
+
+    let (newsdata_io, transport) = async_client(vec![
+        page(&["a", "b"], Some("2")),
+        page(&["c", "d"], Some("3")),
+        page(&["e"], None),
+    ]);
+    let articles = newsdata_io
+        .get_latest_pages(&GetLatestNewsParams::default())
+        .max_articles(3)
+        .articles();
+    assert_eq!(ids(articles).await, ["a", "b", "c"]);
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn async_articles_stop_after_an_error() {
+    let (newsdata_io, transport) = async_client(vec![
+        page(&["a"], Some("2")),
+        HttpResponse::new(
+            500,
+            r#"{"status":"error","results":{"message":"Internal error","code":"ServerError"}}"#,
+        ),
+        page(&["b"], None),
+    ]);
+    let mut articles = newsdata_io
+        .get_latest_pages(&GetLatestNewsParams::default())
+        .articles();
+    assert_eq!(next(&mut articles).await.unwrap().unwrap().article_id, "a");
+    assert!(matches!(
+        next(&mut articles).await,
+        Some(Err(Error::Api { status: 500, .. }))
+    ));
+    assert!(next(&mut articles).await.is_none());
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn async_archive_range_queries_each_window_once() {
+    let (newsdata_io, transport) = async_client(vec![
+        page(&["a", "b"], Some("2")),
+        page(&["c"], None),
+        page(&["c", "d"], None),
+    ]);
+    let from: ArchiveDate = "2024-01-01".parse().unwrap();
+    let to: ArchiveDate = "2024-01-02".parse().unwrap();
+    let progress = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&progress);
+    let articles = newsdata_io
+        .get_news_archive_range(&GetNewsArchiveParams::default(), from..=to, Window::Days(1))
+        .on_progress(move |progress| {
+            recorded.lock().unwrap().push((
+                progress.window,
+                progress.windows,
+                progress.total_pages,
+            ));
+        });
+
+    // The article returned by both windows is dropped the second time
+    assert_eq!(ids(articles).await, ["a", "b", "c", "d"]);
+    assert_eq!(*progress.lock().unwrap(), [(1, 2, 1), (1, 2, 2), (2, 2, 3)]);
+    assert_eq!(
+        param_requested(&transport, "from_date"),
+        ["2024-01-01", "2024-01-01", "2024-01-02"].map(|date| Some(date.to_string()))
+    );
+    assert_eq!(
+        param_requested(&transport, "page"),
+        [None, Some("2".to_string()), None]
+    );
+}
+
+#[tokio::test]
+async fn async_dedup_drops_the_articles_already_seen() {
+    let (newsdata_io, _) = async_client(vec![
+        page(&["a", "b"], Some("2")),
+        page(&["b", "c", "a"], None),
+    ]);
+    let mut deduplicator = Deduplicator::new();
+    let articles = newsdata_io
+        .get_latest_pages(&GetLatestNewsParams::default())
+        .articles();
+    assert_eq!(
+        ids(deduplicator.filter_stream(articles)).await,
+        ["a", "b", "c"]
+    );
+    assert_eq!(deduplicator.store().len(), 3);
+}
+
+#[tokio::test]
+async fn async_watcher_returns_new_articles_until_shut_down() {
+    let (newsdata_io, transport) = async_client(vec![
+        page(&["b", "a"], Some("2")),
+        page(&["d", "c"], Some("3")),
+        page(&["c", "b"], Some("4")),
+    ]);
+    let mut watcher = newsdata_io
+        .watch_latest(&GetLatestNewsParams::default())
+        .interval(Duration::from_millis(10));
+    let shutdown = watcher.shutdown_handle();
+
+    let mut ids = Vec::new();
+    for _ in 0..4 {
+        ids.push(next(&mut watcher).await.unwrap().unwrap().article_id);
+    }
+    // The first poll fetches a single page, the next ones page until a seen article
+    assert_eq!(ids, ["b", "a", "d", "c"]);
+
+    // The poll in progress reaches the seen articles, then the watcher stops instead of sleeping
+    shutdown.shutdown();
+    assert!(next(&mut watcher).await.is_none());
+    assert_eq!(
+        param_requested(&transport, "page"),
+        [None, None, Some("3".to_string())]
+    );
+}
@@ -0,0 +1,139 @@
+mod common;
+
+use newsdata_io_api::newsdata_io::Auth;
+use newsdata_io_api::Error;
+
+fn temp_file(name: &str, content: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("newsdata-io-api-{name}-{}", std::process::id()));
+    std::fs::write(&path, content).unwrap();
+    path
+}
+
+#[test]
+fn key_is_redacted_from_debug_and_serialize() {
+    let auth = Auth::new("secret-key".to_string());
+    assert_eq!(
+        format!("{auth:?}"),
+        r#"Auth { api_key: ApiKey("[REDACTED]") }"#
+    );
+    assert_eq!(
+        serde_json::to_string(&auth).unwrap(),
+        r#"{"api_key":"[REDACTED]"}"#
+    );
+    let auth: Auth = serde_json::from_str(r#"{"api_key":"secret-key"}"#).unwrap();
+    assert_eq!(auth.api_key().unwrap().expose(), "secret-key");
+}
+
+#[test]
+#[allow(deprecated)]
+fn get_api_key_returns_the_errors_of_the_provider() {
+    assert_eq!(
+        Auth::new("secret-key".to_string()).get_api_key().unwrap(),
+        "secret-key"
+    );
+    let auth = Auth::from_provider(|| {
+        Err(Error::Auth {
+            message: "The secret manager is unreachable".to_string(),
+            source: None,
+        })
+    });
+    assert!(matches!(auth.get_api_key(), Err(Error::Auth { .. })));
+}
+
+#[test]
+fn redacted_or_empty_keys_fail_to_deserialize() {
+    let serialized = serde_json::to_string(&Auth::new("secret-key".to_string())).unwrap();
+    let error = serde_json::from_str::<Auth>(&serialized).unwrap_err();
+    assert!(
+        error.to_string().contains("The API key is redacted"),
+        "{error}"
+    );
+    for json in [r#"{"api_key":""}"#, r#"{"api_key":"  "}"#] {
+        let error = serde_json::from_str::<Auth>(json).unwrap_err();
+        assert!(
+            error.to_string().contains("The API key is empty"),
+            "{error}"
+        );
+    }
+}
+
+#[test]
+fn key_is_loaded_from_env_and_file() {
+    std::env::set_var("NEWSDATA_IO_API_TEST_KEY", "env-key");
+    let auth = Auth::from_env("NEWSDATA_IO_API_TEST_KEY").unwrap();
+    assert_eq!(auth.api_key().unwrap().expose(), "env-key");
+    assert_eq!(
+        Auth::from_env("NEWSDATA_IO_API_MISSING_KEY")
+            .unwrap_err()
+            .to_string(),
+        "Auth error: The environment variable `NEWSDATA_IO_API_MISSING_KEY` is not set"
+    );
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStringExt;
+
+        let value = std::ffi::OsString::from_vec(b"secret-\xff-key".to_vec());
+        std::env::set_var("NEWSDATA_IO_API_INVALID_KEY", value);
+        let error = Auth::from_env("NEWSDATA_IO_API_INVALID_KEY").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Auth error: The environment variable `NEWSDATA_IO_API_INVALID_KEY` is not valid unicode"
+        );
+    }
+
+    let path = temp_file("key", "file-key\n");
+    let auth = Auth::from_file(&path).unwrap();
+    assert_eq!(auth.api_key().unwrap().expose(), "file-key");
+    std::fs::write(&path, " \n").unwrap();
+    assert_eq!(
+        Auth::from_file(&path).unwrap_err().to_string(),
+        format!(
+            "Auth error: The API key of the file {} is empty",
+            path.display()
+        )
+    );
+    std::fs::remove_file(&path).unwrap();
+    match Auth::from_file(&path) {
+        Err(error @ Error::Auth { .. }) => {
+            let source = std::error::Error::source(&error).unwrap();
+            let io = source.downcast_ref::<std::io::Error>().unwrap();
+            assert_eq!(io.kind(), std::io::ErrorKind::NotFound);
+        }
+        other => panic!("expected an auth error, got {other:?}"),
+    }
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn provider_is_asked_before_each_request() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use newsdata_io_api::apis::{GetLatestNewsParams, LatestNews};
+    use newsdata_io_api::newsdata_io::{ApiKey, HttpResponse, MockTransport, NewsdataIO};
+
+    use common::EMPTY_PAGE;
+
+    let calls = AtomicUsize::new(0);
+    let auth = Auth::from_provider(move || {
+        let call = calls.fetch_add(1, Ordering::SeqCst);
+        Ok(ApiKey::new(format!("key-{call}")))
+    });
+    assert_eq!(format!("{auth:?}"), "Auth { .. }");
+    let transport = MockTransport::new();
+    transport.push(HttpResponse::new(200, EMPTY_PAGE));
+    transport.push(HttpResponse::new(200, EMPTY_PAGE));
+    let newsdata_io = NewsdataIO::builder(auth)
+        .transport(transport.clone())
+        .build();
+    let params = GetLatestNewsParams::default();
+    newsdata_io.get_latest(&params).unwrap();
+    newsdata_io.get_latest(&params).unwrap();
+    let keys: Vec<String> = transport
+        .requests()
+        .iter()
+        .flat_map(|request| request.headers.clone())
+        .filter(|(name, _)| name == "X-ACCESS-KEY")
+        .map(|(_, value)| value)
+        .collect();
+    assert_eq!(keys, ["key-0", "key-1"]);
+}
@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+use std::thread;
+use std::time::Duration;
+
+use newsdata_io_api::newsdata_io::cache_key;
+use newsdata_io_api::{Cache, CachePolicy, Error, FileCache, Json, MemoryCache};
+
+#[test]
+fn cache_key_sorts_the_query_and_drops_the_api_key() {
+    let query: HashMap<String, String> = [("q", "rust"), ("apikey", "secret"), ("country", "us")]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect();
+    assert_eq!(
+        cache_key("https://newsdata.io/api/1/latest", Some(&query)),
+        "https://newsdata.io/api/1/latest?country=us&q=rust"
+    );
+    assert_eq!(
+        cache_key("https://newsdata.io/api/1/sources", None),
+        "https://newsdata.io/api/1/sources"
+    );
+}
+
+#[test]
+fn cache_key_keeps_distinct_requests_apart() {
+    let key = |endpoint_url: &str, pairs: &[(&str, &str)]| {
+        let query: HashMap<String, String> = pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect();
+        cache_key(endpoint_url, Some(&query))
+    };
+    let latest = "https://newsdata.io/api/1/latest";
+    assert_ne!(
+        key(latest, &[("q", "rust&size=10")]),
+        key(latest, &[("q", "rust"), ("size", "10")])
+    );
+    assert_eq!(
+        key(latest, &[("q", "rust&size=10")]),
+        format!("{latest}?q=rust%26size%3D10")
+    );
+    assert_ne!(
+        key(latest, &[("q", " rust")]),
+        key(latest, &[("q", "rust")])
+    );
+    assert_ne!(
+        key(latest, &[("q", "rust")]),
+        key("http://localhost:8080/api/1/latest", &[("q", "rust")])
+    );
+    assert_ne!(
+        key(latest, &[("q", "rust")]),
+        key("https://newsdata.io/api/2/latest", &[("q", "rust")])
+    );
+}
+
+#[test]
+fn policy_has_per_endpoint_ttls() {
+    let policy = CachePolicy::default().ttl("latest", Duration::ZERO);
+    assert_eq!(policy.ttl_of("sources"), Duration::from_secs(24 * 60 * 60));
+    assert_eq!(policy.ttl_of("latest"), Duration::ZERO);
+    assert_eq!(policy.ttl_of("crypto"), Duration::from_secs(60));
+}
+
+#[test]
+fn memory_cache_expires_and_evicts_entries() {
+    let cache = MemoryCache::new(2);
+    let json = |n: u64| Json::from(n);
+    cache.set("a", &json(1), Duration::from_secs(60)).unwrap();
+    cache.set("b", &json(2), Duration::from_millis(10)).unwrap();
+    assert_eq!(cache.get("a").unwrap(), Some(json(1)));
+    thread::sleep(Duration::from_millis(20));
+    assert_eq!(cache.get("b").unwrap(), None);
+
+    cache.set("b", &json(2), Duration::from_secs(60)).unwrap();
+    // "a" was used after "b" was set, so "b" is evicted first
+    cache.get("a").unwrap();
+    cache.set("c", &json(3), Duration::from_secs(60)).unwrap();
+    assert_eq!(cache.get("b").unwrap(), None);
+    assert_eq!(cache.get("a").unwrap(), Some(json(1)));
+    assert_eq!(cache.len(), 2);
+}
+
+/// Returns an empty directory for the test `name`.
+fn temp_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("newsdata-io-api-{name}-{}", std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn file_cache_stores_responses_under_hashed_paths() {
+    let cache = FileCache::new(temp_dir("file-cache"));
+    let json = Json::from("response");
+    assert_eq!(cache.get("latest?q=rust").unwrap(), None);
+    cache
+        .set("latest?q=rust", &json, Duration::from_secs(60))
+        .unwrap();
+    assert!(cache.path_of("latest?q=rust").is_file());
+    assert_eq!(cache.get("latest?q=rust").unwrap(), Some(json.clone()));
+
+    cache.set("latest?q=go", &json, Duration::ZERO).unwrap();
+    assert_eq!(cache.get("latest?q=go").unwrap(), None);
+    // Offline, expired responses are still served and misses are errors
+    let offline = cache.clone().offline(true);
+    assert_eq!(offline.get("latest?q=go").unwrap(), Some(json));
+    assert!(matches!(
+        offline.get("latest?q=zig"),
+        Err(Error::Cache { key, .. }) if key == "latest?q=zig"
+    ));
+    // A file of another key with the same hash is a miss too
+    let path = cache.path_of("latest?q=zig");
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(
+        &path,
+        r#"{"key":"latest?q=zag","expires_at":null,"response":"other"}"#,
+    )
+    .unwrap();
+    assert_eq!(cache.get("latest?q=zig").unwrap(), None);
+    assert!(matches!(
+        offline.get("latest?q=zig"),
+        Err(Error::Cache { .. })
+    ));
+    std::fs::remove_dir_all(cache.dir()).unwrap();
+}
+
+#[cfg(feature = "blocking")]
+#[test]
+fn offline_client_replays_seeded_fixtures() {
+    use newsdata_io_api::{
+        Auth, CachePolicy, GetLatestNewsParams, LatestNews, NewsdataIO, ToQuery,
+    };
+
+    let dir = temp_dir("offline");
+    let cache = FileCache::new(&dir);
+    let params = GetLatestNewsParams::builder().q("rust").build().unwrap();
+    let fixture = serde_json::json!({
+        "status": "success",
+        "totalResults": 1,
+        "results": [{"article_id": "a"}],
+        "nextPage": null
+    });
+    cache
+        .seed(
+            "http://127.0.0.1:9/1/latest",
+            Some(&params.to_query()),
+            &fixture,
+        )
+        .unwrap();
+    // No server is listening, so any request would fail with a transport error.
+    // A zero TTL does not bypass an offline cache.
+    let newsdata_io = NewsdataIO::builder(Auth::new("test-key".to_string()))
+        .base_url("http://127.0.0.1:9")
+        .cache(cache.offline(true))
+        .cache_policy(CachePolicy::default().ttl("latest", Duration::ZERO))
+        .build();
+    let response = newsdata_io.get_latest(&params).unwrap();
+    assert_eq!(response.results[0].article_id, "a");
+    let other = GetLatestNewsParams::builder().q("go").build().unwrap();
+    assert!(matches!(
+        newsdata_io.get_latest(&other),
+        Err(Error::Cache { .. })
+    ));
+    std::fs::remove_dir_all(dir).unwrap();
+}
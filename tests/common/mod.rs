@@ -0,0 +1,78 @@
+//! Fixtures shared by the integration tests.
+// Each test crate uses only some of them
+#![allow(dead_code)]
+
+#[cfg(feature = "async")]
+use newsdata_io_api::newsdata_io::AsyncNewsdataIO;
+#[cfg(feature = "blocking")]
+use newsdata_io_api::newsdata_io::NewsdataIO;
+use newsdata_io_api::newsdata_io::{Auth, HttpResponse, MockTransport, RetryPolicy};
+
+/// Body of a successful response without articles.
+pub const EMPTY_PAGE: &str =
+    r#"{"status":"success","totalResults":0,"results":[],"nextPage":null}"#;
+
+/// Body of a successful response holding the articles `ids`, followed by `next_page` if any.
+pub fn page_body(ids: &[&str], next_page: Option<&str>) -> String {
+    let results: Vec<String> = ids
+        .iter()
+        .map(|id| format!(r#"{{"article_id":"{id}"}}"#))
+        .collect();
+    format!(
+        r#"{{"status":"success","totalResults":{},"results":[{}],"nextPage":{}}}"#,
+        ids.len(),
+        results.join(","),
+        next_page.map_or("null".to_string(), |page| format!(r#""{page}""#))
+    )
+}
+
+/// Successful response holding the articles `ids`, see `page_body`.
+pub fn page(ids: &[&str], next_page: Option<&str>) -> HttpResponse {
+    HttpResponse::new(200, page_body(ids, next_page))
+}
+
+/// Returns a transport answering with `responses`, in order.
+pub fn mock(responses: Vec<HttpResponse>) -> MockTransport {
+    let transport = MockTransport::new();
+    for response in responses {
+        transport.push(response);
+    }
+    transport
+}
+
+/// Returns a client without retries answering with `responses`, in order.
+#[cfg(feature = "blocking")]
+pub fn client(responses: Vec<HttpResponse>) -> (NewsdataIO, MockTransport) {
+    let transport = mock(responses);
+    let newsdata_io = NewsdataIO::builder(Auth::new("test-key".to_string()))
+        .transport(transport.clone())
+        .retry_policy(RetryPolicy::none())
+        .build();
+    (newsdata_io, transport)
+}
+
+/// Async counterpart of `client`.
+#[cfg(feature = "async")]
+pub fn async_client(responses: Vec<HttpResponse>) -> (AsyncNewsdataIO, MockTransport) {
+    let transport = mock(responses);
+    let newsdata_io = AsyncNewsdataIO::builder(Auth::new("test-key".to_string()))
+        .async_transport(transport.clone())
+        .retry_policy(RetryPolicy::none())
+        .build_async();
+    (newsdata_io, transport)
+}
+
+/// Value of the query parameter `name` of each request sent.
+pub fn param_requested(transport: &MockTransport, name: &str) -> Vec<Option<String>> {
+    transport
+        .requests()
+        .iter()
+        .map(|request| {
+            request
+                .query
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+        })
+        .collect()
+}
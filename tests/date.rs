@@ -0,0 +1,168 @@
+use newsdata_io_api::apis::{
+    ArchiveDate, GetLatestNewsParams, GetNewsArchiveParams, Timeframe, ToQuery,
+};
+use newsdata_io_api::Error;
+
+#[test]
+fn formats_dates_and_timeframes_in_api_syntax() {
+    let params = GetNewsArchiveParams::builder()
+        .from_date("2024-02-29")
+        .to_date(
+            ArchiveDate::from_ymd(2024, 3, 1)
+                .unwrap()
+                .and_hms(8, 5, 0)
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let query = params.to_query();
+    assert_eq!(query["from_date"], "2024-02-29");
+    assert_eq!(query["to_date"], "2024-03-01 08:05:00");
+
+    let params = GetLatestNewsParams::builder()
+        .timeframe("15m")
+        .build()
+        .unwrap();
+    assert_eq!(params.timeframe, Some(Timeframe::Minutes(15)));
+    assert_eq!(params.to_query()["timeframe"], "15m");
+    assert_eq!(Timeframe::hours(6).unwrap().to_string(), "6");
+}
+
+#[test]
+fn rejects_malformed_and_out_of_range_values() {
+    for date in [
+        "2023-02-29",
+        "2024-13-01",
+        "2024-1-01",
+        "01/02/2024",
+        "2024-01-01 24:00:00",
+    ] {
+        assert!(date.parse::<ArchiveDate>().is_err(), "{date}");
+    }
+    for timeframe in ["0", "49", "2881m", "6h", ""] {
+        assert!(timeframe.parse::<Timeframe>().is_err(), "{timeframe}");
+    }
+    assert!(Timeframe::minutes(2880).is_ok());
+
+    let params = GetLatestNewsParams {
+        timeframe: Some(Timeframe::Hours(72)),
+        ..Default::default()
+    };
+    assert!(ToQuery::to_query(&params).contains_key("timeframe"));
+    assert!(newsdata_io_api::Validate::validate(&params).is_err());
+}
+
+#[test]
+fn builder_reports_every_invalid_date() {
+    let result = GetNewsArchiveParams::builder()
+        .from_date("2024-02-30")
+        .to_date("yesterday")
+        .build();
+    match result {
+        Err(Error::InvalidParameter { violations, .. }) => {
+            let fields: Vec<&str> = violations.iter().map(|v| v.field.as_str()).collect();
+            assert_eq!(fields, ["from_date", "to_date"]);
+        }
+        other => panic!("expected an invalid parameter error, got {other:?}"),
+    }
+
+    let result = GetNewsArchiveParams::builder()
+        .from_date("2024-03-02")
+        .to_date("2024-03-01")
+        .build();
+    assert!(matches!(
+        result,
+        Err(Error::InvalidParameter { field: Some(field), .. }) if field == "from_date"
+    ));
+}
+
+#[test]
+fn dates_without_time_cover_the_whole_day() {
+    let accepted = |from: &str, to: &str| {
+        GetNewsArchiveParams::builder()
+            .from_date(from)
+            .to_date(to)
+            .build()
+            .is_ok()
+    };
+    assert!(accepted("2024-01-01 10:00:00", "2024-01-01"));
+    assert!(accepted("2024-01-01", "2024-01-01 08:00:00"));
+    assert!(accepted("2024-01-01 08:00:00", "2024-01-01 08:00:00"));
+    assert!(!accepted("2024-01-01 10:00:00", "2024-01-01 09:59:59"));
+    assert!(!accepted("2024-01-02", "2024-01-01 23:59:59"));
+
+    let date = |value: &str| value.parse::<ArchiveDate>().unwrap();
+    assert!(date("2024-01-01") < date("2024-01-01 00:00:00"));
+    assert!(date("2024-01-01 23:59:59") < date("2024-01-02"));
+    assert!(!date("2024-01-01 10:00:00").is_after(&date("2024-01-01")));
+    assert!(date("2024-01-02").is_after(&date("2024-01-01 23:59:59")));
+}
+
+#[test]
+fn error_messages_name_each_field_once() {
+    assert_eq!(
+        Timeframe::hours(49).unwrap_err().to_string(),
+        "Invalid parameter `timeframe`: must be 1 to 48 hours or 1 to 2880 minutes, got `49`"
+    );
+    let error = GetNewsArchiveParams::builder()
+        .from_date("2024-02-30")
+        .to_date("yesterday")
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Invalid parameter: from_date: invalid date `2024-02-30`, expected YYYY-MM-DD or YYYY-MM-DD HH:MM:SS; \
+         to_date: invalid date `yesterday`, expected YYYY-MM-DD or YYYY-MM-DD HH:MM:SS"
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn converts_chrono_values() {
+    let date = chrono::NaiveDate::from_ymd_opt(2024, 5, 17).unwrap();
+    assert_eq!(
+        ArchiveDate::try_from(date).unwrap().to_string(),
+        "2024-05-17"
+    );
+    let datetime = date.and_hms_opt(13, 45, 30).unwrap();
+    assert_eq!(
+        ArchiveDate::try_from(datetime).unwrap().to_string(),
+        "2024-05-17 13:45:30"
+    );
+    let params = GetNewsArchiveParams::builder()
+        .from_date(date)
+        .build()
+        .unwrap();
+    assert_eq!(params.from_date, Some(ArchiveDate::try_from(date).unwrap()));
+
+    // Out of the 1 to 9999 years accepted by the API
+    for year in [-5, 0, 10_000] {
+        let date = chrono::NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
+        assert!(matches!(
+            ArchiveDate::try_from(date),
+            Err(Error::InvalidParameter { .. })
+        ));
+        assert!(GetNewsArchiveParams::builder()
+            .from_date(date)
+            .build()
+            .is_err());
+    }
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn converts_time_values() {
+    let date = time::Date::from_calendar_date(2024, time::Month::May, 17).unwrap();
+    assert_eq!(
+        ArchiveDate::try_from(date).unwrap().to_string(),
+        "2024-05-17"
+    );
+    let datetime = date.with_hms(13, 45, 30).unwrap();
+    assert_eq!(
+        ArchiveDate::try_from(datetime).unwrap().to_string(),
+        "2024-05-17 13:45:30"
+    );
+
+    let date = time::Date::from_calendar_date(-5, time::Month::January, 1).unwrap();
+    assert!(ArchiveDate::try_from(date).is_err());
+}
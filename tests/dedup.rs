@@ -0,0 +1,95 @@
+use newsdata_io_api::{ApiResult, Article, DedupKey, Deduplicator, Error};
+
+fn article(id: &str, title: &str, link: &str) -> Article {
+    Article {
+        article_id: id.to_string(),
+        title: Some(title.to_string()),
+        link: Some(link.to_string()),
+        ..Default::default()
+    }
+}
+
+fn ids(articles: impl Iterator<Item = ApiResult<Article>>) -> Vec<String> {
+    articles
+        .map(|article| article.map_or_else(|e| e.to_string(), |article| article.article_id))
+        .collect()
+}
+
+#[test]
+fn drops_repeated_ids_and_links() {
+    let mut deduplicator = Deduplicator::new();
+    assert_eq!(
+        ids(deduplicator.filter(repeated_articles())),
+        ["1", "3", "4"]
+    );
+}
+
+#[test]
+fn drops_repeated_titles_when_opted_in() {
+    let mut deduplicator = Deduplicator::new().keys(DedupKey::ALL);
+    assert_eq!(ids(deduplicator.filter(repeated_articles())), ["1", "4"]);
+}
+
+/// Articles repeating the id, the link then the title of the first one.
+fn repeated_articles() -> Vec<ApiResult<Article>> {
+    vec![
+        Ok(article(
+            "1",
+            "Rust 2.0 released",
+            "https://example.com/rust",
+        )),
+        Ok(article("1", "Another title", "https://example.com/other")),
+        Ok(article(
+            "2",
+            "Unrelated",
+            "http://www.Example.com/rust/?utm_source=x#top",
+        )),
+        Ok(article(
+            "3",
+            "RUST 2.0 released!",
+            "https://mirror.net/rust",
+        )),
+        Ok(article("4", "Something else", "https://example.com/else")),
+    ]
+}
+
+#[test]
+fn uses_only_the_selected_keys() {
+    let articles = vec![
+        Ok(article("1", "Same title", "https://example.com/a")),
+        Ok(article("2", "Same title", "https://example.com/b")),
+    ];
+    let mut deduplicator = Deduplicator::new().keys([DedupKey::ArticleId]);
+    assert_eq!(ids(deduplicator.filter(articles)), ["1", "2"]);
+}
+
+#[test]
+fn passes_errors_through() {
+    let error = Error::Transport {
+        message: "reset".to_string(),
+        source: None,
+    };
+    let articles = vec![
+        Ok(article("1", "a", "https://example.com/a")),
+        Err(error),
+        Ok(article("1", "a", "https://example.com/a")),
+    ];
+    let mut deduplicator = Deduplicator::new();
+    assert_eq!(
+        ids(deduplicator.filter(articles)),
+        ["1", "Transport error: reset"]
+    );
+}
+
+#[test]
+fn bounded_store_forgets_least_recently_seen() {
+    let mut deduplicator = Deduplicator::bounded(2).keys([DedupKey::ArticleId]);
+    let mut is_new = |id: &str| deduplicator.is_new(&article(id, "", ""));
+    assert!(is_new("1"));
+    assert!(is_new("2"));
+    assert!(!is_new("1"));
+    assert!(is_new("3"));
+    assert!(is_new("2"));
+    assert!(!is_new("3"));
+    assert_eq!(deduplicator.store().len(), 2);
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use common::EMPTY_PAGE;
+use newsdata_io_api::apis::{GetLatestNewsParams, LatestNews};
+use newsdata_io_api::newsdata_io::{
+    Auth, HttpRequest, HttpResponse, Middleware, MockTransport, NewsdataIO, RetryPolicy,
+};
+use newsdata_io_api::{ApiResult, Error};
+
+/// Adds a header to the requests and logs the hook calls.
+struct Tag {
+    name: &'static str,
+    calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl Middleware for Tag {
+    fn on_request(&self, request: &mut HttpRequest) -> Option<ApiResult<HttpResponse>> {
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("request {}", self.name));
+        request
+            .headers
+            .push(("X-Tag".to_string(), self.name.to_string()));
+        None
+    }
+
+    fn on_response(&self, _: &HttpRequest, response: &mut ApiResult<HttpResponse>) {
+        let status = response.as_ref().map_or(0, |response| response.status);
+        self.calls
+            .lock()
+            .unwrap()
+            .push(format!("response {} {status}", self.name));
+    }
+}
+
+/// Answers every request without sending it.
+struct Canned;
+
+impl Middleware for Canned {
+    fn on_request(&self, _: &mut HttpRequest) -> Option<ApiResult<HttpResponse>> {
+        Some(Ok(HttpResponse::new(200, EMPTY_PAGE)))
+    }
+}
+
+#[test]
+fn middlewares_wrap_each_request_in_order() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let transport = MockTransport::new();
+    transport.push(HttpResponse::new(200, EMPTY_PAGE));
+    let newsdata_io = NewsdataIO::builder(Auth::new("test-key".to_string()))
+        .transport(transport.clone())
+        .middleware(Tag {
+            name: "a",
+            calls: calls.clone(),
+        })
+        .middleware(Tag {
+            name: "b",
+            calls: calls.clone(),
+        })
+        .build();
+    newsdata_io
+        .get_latest(&GetLatestNewsParams::default())
+        .unwrap();
+    assert_eq!(
+        *calls.lock().unwrap(),
+        ["request a", "request b", "response b 200", "response a 200"]
+    );
+    let headers = &transport.requests()[0].headers;
+    assert!(headers.contains(&("X-Tag".to_string(), "a".to_string())));
+    assert!(headers.contains(&("X-Tag".to_string(), "b".to_string())));
+}
+
+#[test]
+fn middleware_can_answer_without_sending() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let transport = MockTransport::new();
+    let newsdata_io = NewsdataIO::builder(Auth::new("test-key".to_string()))
+        .transport(transport.clone())
+        .retry_policy(RetryPolicy::none())
+        .middleware(Tag {
+            name: "a",
+            calls: calls.clone(),
+        })
+        .middleware(Canned)
+        .middleware(Tag {
+            name: "b",
+            calls: calls.clone(),
+        })
+        .build();
+    let response = newsdata_io
+        .get_latest(&GetLatestNewsParams::default())
+        .unwrap();
+    assert_eq!(response.status, "success");
+    assert!(transport.requests().is_empty());
+    assert_eq!(*calls.lock().unwrap(), ["request a", "response a 200"]);
+}
+
+/// Turns the failures to get a response into an error response.
+struct Fallback;
+
+impl Middleware for Fallback {
+    fn on_response(&self, _: &HttpRequest, response: &mut ApiResult<HttpResponse>) {
+        if response.is_err() {
+            *response = Ok(HttpResponse::new(
+                401,
+                r#"{"status":"error","results":{"message":"Invalid key","code":"Unauthorized"}}"#,
+            ));
+        }
+    }
+}
+
+#[test]
+fn middleware_can_replace_an_error() {
+    let newsdata_io = NewsdataIO::builder(Auth::new("test-key".to_string()))
+        .transport(MockTransport::new())
+        .retry_policy(RetryPolicy::none())
+        .middleware(Fallback)
+        .build();
+    let error = newsdata_io
+        .get_latest(&GetLatestNewsParams::default())
+        .unwrap_err();
+    assert!(matches!(error, Error::Unauthorized { .. }));
+}
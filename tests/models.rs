@@ -0,0 +1,112 @@
+use newsdata_io_api::{NewsResponse, SourcesResponse};
+use serde_json::json;
+
+fn latest_response() -> serde_json::Value {
+    json!({
+        "status": "success",
+        "totalResults": 2,
+        "results": [
+            {
+                "article_id": "f3c1c6f5a6e1b8d0",
+                "title": "Rust 1.80 released",
+                "link": "https://example.com/rust-1-80",
+                "keywords": ["rust", "release"],
+                "creator": null,
+                "video_url": null,
+                "description": "The Rust team announced a new version.",
+                "content": "ONLY AVAILABLE IN PAID PLANS",
+                "pubDate": "2024-07-25 14:30:00",
+                "pubDateTZ": "UTC",
+                "image_url": null,
+                "source_id": "example",
+                "source_priority": 12345,
+                "source_name": "Example",
+                "source_url": "https://example.com",
+                "source_icon": "https://example.com/icon.png",
+                "language": "english",
+                "country": ["united states of america"],
+                "category": ["technology"],
+                "ai_tag": "ONLY AVAILABLE IN PROFESSIONAL AND CORPORATE PLANS",
+                "sentiment": "positive",
+                "sentiment_stats": {"positive": 90.1, "neutral": 9.2, "negative": 0.7},
+                "ai_region": "ONLY AVAILABLE IN CORPORATE PLANS",
+                "ai_org": "ONLY AVAILABLE IN CORPORATE PLANS",
+                "duplicate": false,
+                "ai_summary": "A new Rust release."
+            },
+            {
+                "article_id": "0b9e2d7c4a1f3e58",
+                "title": "Minimal article"
+            }
+        ],
+        "nextPage": "1721918400123456789",
+        "rateLimit": {"remaining": 199}
+    })
+}
+
+#[test]
+fn deserializes_a_news_response_keeping_unknown_fields() {
+    let response: NewsResponse = serde_json::from_value(latest_response()).unwrap();
+    assert_eq!(response.status, "success");
+    assert_eq!(response.total_results, 2);
+    assert_eq!(response.next_page.as_deref(), Some("1721918400123456789"));
+    assert_eq!(response.extra["rateLimit"], json!({"remaining": 199}));
+
+    let article = &response.results[0];
+    assert_eq!(article.title.as_deref(), Some("Rust 1.80 released"));
+    assert_eq!(article.pub_date.as_deref(), Some("2024-07-25 14:30:00"));
+    assert_eq!(article.source_priority, Some(12345));
+    assert_eq!(article.category, Some(vec!["technology".to_string()]));
+    assert_eq!(article.creator, None);
+    assert_eq!(article.duplicate, Some(false));
+    assert_eq!(
+        article.ai_tag,
+        Some(json!("ONLY AVAILABLE IN PROFESSIONAL AND CORPORATE PLANS"))
+    );
+    assert_eq!(article.sentiment_stats.as_ref().unwrap()["positive"], 90.1);
+    assert_eq!(article.extra.len(), 1);
+    assert_eq!(article.extra["ai_summary"], "A new Rust release.");
+
+    let minimal = &response.results[1];
+    assert_eq!(minimal.article_id, "0b9e2d7c4a1f3e58");
+    assert_eq!(minimal.link, None);
+    assert!(minimal.extra.is_empty());
+}
+
+#[test]
+fn unknown_fields_survive_a_round_trip() {
+    let response: NewsResponse = serde_json::from_value(latest_response()).unwrap();
+    let json = serde_json::to_value(&response).unwrap();
+    assert_eq!(json["rateLimit"], json!({"remaining": 199}));
+    assert_eq!(json["results"][0]["ai_summary"], "A new Rust release.");
+    assert_eq!(json["results"][0]["pubDate"], "2024-07-25 14:30:00");
+    assert_eq!(
+        serde_json::from_value::<NewsResponse>(json).unwrap(),
+        response
+    );
+
+    let sources: SourcesResponse = serde_json::from_value(json!({
+        "status": "success",
+        "totalResults": 1,
+        "results": [{
+            "id": "example",
+            "name": "Example",
+            "url": "https://example.com",
+            "priority": 12345,
+            "category": ["technology"],
+            "language": ["english"],
+            "country": ["united states of america"],
+            "last_fetch": "2024-07-25 14:00:00",
+            "total_article": 4321
+        }]
+    }))
+    .unwrap();
+    assert_eq!(sources.results[0].priority, Some(12345));
+    assert_eq!(sources.results[0].extra["total_article"], 4321);
+    let json = serde_json::to_value(&sources).unwrap();
+    assert_eq!(json["results"][0]["total_article"], 4321);
+    assert_eq!(
+        serde_json::from_value::<SourcesResponse>(json).unwrap(),
+        sources
+    );
+}
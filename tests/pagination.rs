@@ -0,0 +1,100 @@
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use common::{client, page, param_requested};
+use newsdata_io_api::apis::{GetLatestNewsParams, LatestNews};
+use newsdata_io_api::newsdata_io::HttpResponse;
+use newsdata_io_api::{ApiResult, Article, Error};
+
+fn ids(articles: impl Iterator<Item = ApiResult<Article>>) -> Vec<String> {
+    articles
+        .map(|article| article.unwrap().article_id)
+        .collect()
+}
+
+#[test]
+fn max_pages_stops_following_the_cursor() {
+    let (newsdata_io, transport) = client(vec![
+        page(&["a", "b"], Some("2")),
+        page(&["c", "d"], Some("3")),
+        page(&["e"], None),
+    ]);
+    let pages: Vec<_> = newsdata_io
+        .get_latest_pages(&GetLatestNewsParams::default())
+        .max_pages(2)
+        .map(|page| page.unwrap().results.len())
+        .collect();
+    assert_eq!(pages, [2, 2]);
+    assert_eq!(
+        param_requested(&transport, "page"),
+        [None, Some("2".to_string())]
+    );
+}
+
+#[test]
+fn max_articles_cuts_the_last_page_short() {
+    let (newsdata_io, transport) = client(vec![
+        page(&["a", "b"], Some("2")),
+        page(&["c", "d"], Some("3")),
+        page(&["e", "f"], None),
+    ]);
+    let articles = newsdata_io
+        .get_latest_pages(&GetLatestNewsParams::default())
+        .max_articles(3)
+        .articles();
+    assert_eq!(ids(articles), ["a", "b", "c"]);
+    assert_eq!(transport.requests().len(), 2);
+
+    // A limit reached at the end of a page does not fetch the next one
+    let (newsdata_io, transport) = client(vec![page(&["a", "b"], Some("2")), page(&["c"], None)]);
+    let articles = newsdata_io
+        .get_latest_pages(&GetLatestNewsParams::default())
+        .max_articles(2)
+        .articles();
+    assert_eq!(ids(articles), ["a", "b"]);
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[test]
+fn stops_on_an_empty_page_or_a_missing_cursor() {
+    let (newsdata_io, transport) = client(vec![
+        page(&["a"], Some("2")),
+        page(&[], Some("3")),
+        page(&["b"], None),
+    ]);
+    let articles = newsdata_io
+        .get_latest_pages(&GetLatestNewsParams::default())
+        .articles();
+    assert_eq!(ids(articles), ["a"]);
+    assert_eq!(transport.requests().len(), 2);
+
+    let (newsdata_io, transport) = client(vec![page(&["a", "b"], None), page(&["c"], None)]);
+    let articles = newsdata_io
+        .get_latest_pages(&GetLatestNewsParams::default())
+        .articles();
+    assert_eq!(ids(articles), ["a", "b"]);
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[test]
+fn stops_after_an_error() {
+    let (newsdata_io, transport) = client(vec![
+        page(&["a"], Some("2")),
+        HttpResponse::new(
+            500,
+            r#"{"status":"error","results":{"message":"Internal error","code":"ServerError"}}"#,
+        ),
+        page(&["b"], None),
+    ]);
+    let mut articles = newsdata_io
+        .get_latest_pages(&GetLatestNewsParams::default())
+        .articles();
+    assert_eq!(articles.next().unwrap().unwrap().article_id, "a");
+    assert!(matches!(
+        articles.next(),
+        Some(Err(Error::Api { status: 500, .. }))
+    ));
+    assert!(articles.next().is_none());
+    assert_eq!(transport.requests().len(), 2);
+}
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use newsdata_io_api::apis::{
+    Category, Country, GetLatestNewsParams, GetNewsSourcesParams, ToQuery, Validate,
+};
+use newsdata_io_api::Error;
+
+#[test]
+fn encodes_renamed_fields_and_lists() {
+    let params = GetLatestNewsParams::builder()
+        .q_in_title("rust")
+        .country([Country::UnitedStates, Country::HongKong])
+        .exclude_category(Category::Sports)
+        .domain(["bbc", "cnn"])
+        .size(10)
+        .build()
+        .unwrap();
+    let expected: HashMap<String, String> = [
+        ("qInTitle", "rust"),
+        ("country", "us,hk"),
+        ("excludecategory", "sports"),
+        ("domain", "bbc,cnn"),
+        ("size", "10"),
+    ]
+    .into_iter()
+    .map(|(key, value)| (key.to_string(), value.to_string()))
+    .collect();
+    assert_eq!(params.to_query(), expected);
+}
+
+#[test]
+fn skips_unset_fields() {
+    assert!(GetNewsSourcesParams::default().to_query().is_empty());
+}
+
+#[test]
+fn empty_lists_are_rejected_instead_of_sent_empty() {
+    let result = GetLatestNewsParams::builder()
+        .category(Vec::<Category>::new())
+        .domain(Vec::<String>::new())
+        .exclude_field([""])
+        .build();
+    match result {
+        Err(Error::InvalidParameter { violations, .. }) => {
+            let fields: Vec<&str> = violations.iter().map(|v| v.field.as_str()).collect();
+            assert_eq!(fields, ["category", "domain", "exclude_field"]);
+            assert_eq!(
+                violations[0].message,
+                "must not be empty, leave it unset instead"
+            );
+        }
+        other => panic!("expected an invalid parameter error, got {other:?}"),
+    }
+
+    let params = GetLatestNewsParams {
+        country: Some(Vec::new()),
+        ..Default::default()
+    };
+    assert!(params.validate().is_err());
+}
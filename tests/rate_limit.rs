@@ -0,0 +1,70 @@
+use std::thread;
+use std::time::Duration;
+
+use newsdata_io_api::newsdata_io::{RateLimit, RateLimiter};
+
+/// Asserts that `wait` is `expected`, give or take the time elapsed while testing.
+fn assert_wait(wait: Duration, expected: Duration) {
+    let tolerance = Duration::from_millis(50);
+    assert!(
+        wait <= expected && wait + tolerance >= expected,
+        "waited {wait:?}, expected {expected:?}"
+    );
+}
+
+#[test]
+fn burst_is_free_then_requests_are_spaced() {
+    let limiter = RateLimiter::new([RateLimit::new(10, Duration::from_secs(10)).burst(3)]);
+    for _ in 0..3 {
+        assert_eq!(limiter.reserve(), Duration::ZERO);
+    }
+    // Each queued request waits one more token, regained every second
+    assert_wait(limiter.reserve(), Duration::from_secs(1));
+    assert_wait(limiter.reserve(), Duration::from_secs(2));
+}
+
+#[test]
+fn tokens_refill_over_time_up_to_the_burst() {
+    let limiter = RateLimiter::new([RateLimit::new(100, Duration::from_secs(1)).burst(1)]);
+    assert_eq!(limiter.reserve(), Duration::ZERO);
+    assert_wait(limiter.reserve(), Duration::from_millis(10));
+
+    // Long enough to regain more than the burst, which caps the tokens
+    thread::sleep(Duration::from_millis(60));
+    assert_eq!(limiter.reserve(), Duration::ZERO);
+    assert_wait(limiter.reserve(), Duration::from_millis(10));
+}
+
+#[test]
+fn combined_limits_wait_for_the_strictest() {
+    let limiter = RateLimiter::new([
+        RateLimit::new(1, Duration::from_secs(1)),
+        RateLimit::new(2, Duration::from_secs(20)),
+    ]);
+    assert_eq!(limiter.reserve(), Duration::ZERO);
+    // The per second limit is exhausted first
+    assert_wait(limiter.reserve(), Duration::from_secs(1));
+    // Then the per 20 seconds one, which regains a token every 10 seconds
+    assert_wait(limiter.reserve(), Duration::from_secs(10));
+}
+
+#[test]
+fn clones_share_the_same_buckets() {
+    let limiter = RateLimiter::new([RateLimit::new(2, Duration::from_secs(2))]);
+    let clone = limiter.clone();
+    assert_eq!(limiter.reserve(), Duration::ZERO);
+    assert_eq!(clone.reserve(), Duration::ZERO);
+    assert_wait(limiter.reserve(), Duration::from_secs(1));
+    assert_wait(clone.reserve(), Duration::from_secs(2));
+}
+
+#[test]
+fn empty_limits_never_wait() {
+    let limiter = RateLimiter::new([
+        RateLimit::new(0, Duration::from_secs(1)),
+        RateLimit::new(1, Duration::ZERO),
+    ]);
+    for _ in 0..5 {
+        assert_eq!(limiter.reserve(), Duration::ZERO);
+    }
+}
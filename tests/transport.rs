@@ -0,0 +1,262 @@
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use std::time::Duration;
+
+use common::EMPTY_PAGE;
+use newsdata_io_api::apis::{GetLatestNewsParams, LatestNews};
+use newsdata_io_api::newsdata_io::{
+    Auth, AuthMode, HttpResponse, MockTransport, NewsdataIO, NewsdataIOBuilder, RetryPolicy,
+};
+use newsdata_io_api::Error;
+
+fn builder(transport: &MockTransport) -> NewsdataIOBuilder {
+    NewsdataIO::builder(Auth::new("test-key".to_string()))
+        .transport(transport.clone())
+        .retry_policy(RetryPolicy {
+            base_delay: Duration::from_millis(1),
+            ..Default::default()
+        })
+}
+
+#[test]
+fn requests_go_through_the_transport() {
+    let transport = MockTransport::new();
+    transport
+        .push(HttpResponse::new(200, EMPTY_PAGE).with_header("Content-Type", "application/json"));
+    let params = GetLatestNewsParams::builder().q("rust").build().unwrap();
+    let response = builder(&transport).build().get_latest(&params).unwrap();
+    assert_eq!(response.status, "success");
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].url, "https://newsdata.io/api/1/latest");
+    assert!(requests[0]
+        .headers
+        .contains(&("X-ACCESS-KEY".to_string(), "test-key".to_string())));
+    assert_eq!(requests[0].query, [("q".to_string(), "rust".to_string())]);
+}
+
+#[test]
+fn query_auth_mode_sends_the_key_as_a_parameter() {
+    let transport = MockTransport::new();
+    transport.push(HttpResponse::new(200, EMPTY_PAGE));
+    builder(&transport)
+        .auth_mode(AuthMode::Query)
+        .build()
+        .get_latest(&GetLatestNewsParams::default())
+        .unwrap();
+    let request = &transport.requests()[0];
+    assert_eq!(
+        request.query,
+        [("apikey".to_string(), "test-key".to_string())]
+    );
+    assert!(request
+        .headers
+        .iter()
+        .all(|(name, _)| name != "X-ACCESS-KEY"));
+}
+
+#[test]
+fn api_key_is_redacted_from_request_debug() {
+    let transport = MockTransport::new();
+    transport.push(HttpResponse::new(200, EMPTY_PAGE));
+    transport.push(HttpResponse::new(200, EMPTY_PAGE));
+    let params = GetLatestNewsParams::builder().q("rust").build().unwrap();
+    builder(&transport).build().get_latest(&params).unwrap();
+    builder(&transport)
+        .auth_mode(AuthMode::Query)
+        .build()
+        .get_latest(&params)
+        .unwrap();
+    for request in transport.requests() {
+        let debug = format!("{request:?}");
+        assert!(!debug.contains("test-key"), "{debug}");
+        assert!(debug.contains("[REDACTED]"), "{debug}");
+        assert!(debug.contains("rust"), "{debug}");
+    }
+}
+
+#[test]
+fn api_key_is_redacted_from_errors() {
+    let transport = MockTransport::new();
+    transport.push_error(Error::Transport {
+        message: "https://newsdata.io/api/1/latest?apikey=test-key: Connection refused".to_string(),
+        source: Some("apikey=test-key".into()),
+    });
+    let error = builder(&transport)
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .get_latest(&GetLatestNewsParams::default())
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Transport error: https://newsdata.io/api/1/latest?apikey=[REDACTED]: Connection refused"
+    );
+    assert!(std::error::Error::source(&error).is_none());
+}
+
+#[test]
+fn transport_errors_and_error_statuses_are_retried() {
+    let transport = MockTransport::new();
+    transport.push_error(Error::Transport {
+        message: "connection reset".to_string(),
+        source: None,
+    });
+    transport.push(HttpResponse::new(503, "<html>503</html>").with_header("Retry-After", "0"));
+    transport.push(HttpResponse::new(200, EMPTY_PAGE));
+    let response = builder(&transport)
+        .build()
+        .get_latest(&GetLatestNewsParams::default())
+        .unwrap();
+    assert_eq!(response.status, "success");
+    assert_eq!(transport.requests().len(), 3);
+}
+
+#[test]
+fn retry_after_longer_than_the_max_delay_is_not_retried() {
+    let transport = MockTransport::new();
+    transport.push(
+        error_response(503, "ServiceUnavailable", "Maintenance")
+            .with_header("Retry-After", "Fri, 01 Jan 2100 00:00:00 GMT"),
+    );
+    transport.push(HttpResponse::new(200, EMPTY_PAGE));
+    let error = builder(&transport)
+        .build()
+        .get_latest(&GetLatestNewsParams::default())
+        .unwrap_err();
+    assert!(matches!(error, Error::Api { status: 503, .. }));
+    assert!(error.retry_after().unwrap() > Duration::from_secs(60 * 60 * 24 * 365));
+    assert_eq!(transport.requests().len(), 1);
+
+    // A delay in seconds is given up on the same way
+    let transport = MockTransport::new();
+    transport.push(
+        error_response(429, "RateLimitExceeded", "Slow down").with_header("Retry-After", "3600"),
+    );
+    let error = builder(&transport)
+        .build()
+        .get_latest(&GetLatestNewsParams::default())
+        .unwrap_err();
+    assert_eq!(error.retry_after(), Some(Duration::from_secs(3600)));
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[test]
+fn retry_after_is_parsed_from_http_dates() {
+    // A date in the past is no delay, in any of the forms of RFC 9110
+    for date in [
+        "Sun, 06 Nov 1994 08:49:37 GMT",
+        "Sunday, 06-Nov-94 08:49:37 GMT",
+        "Sun Nov  6 08:49:37 1994",
+    ] {
+        let transport = MockTransport::new();
+        transport.push(
+            error_response(503, "ServiceUnavailable", "Maintenance")
+                .with_header("Retry-After", date),
+        );
+        transport.push(HttpResponse::new(200, EMPTY_PAGE));
+        builder(&transport)
+            .build()
+            .get_latest(&GetLatestNewsParams::default())
+            .unwrap();
+        assert_eq!(transport.requests().len(), 2, "{date}");
+    }
+
+    let transport = MockTransport::new();
+    transport.push(
+        error_response(503, "ServiceUnavailable", "Maintenance")
+            .with_header("Retry-After", "Sun, 06 Nov 1994 08:49:37 GMT"),
+    );
+    let error = builder(&transport)
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .get_latest(&GetLatestNewsParams::default())
+        .unwrap_err();
+    assert_eq!(error.retry_after(), Some(Duration::ZERO));
+}
+
+#[test]
+fn empty_queue_is_a_transport_error() {
+    let transport = MockTransport::new();
+    let error = builder(&transport)
+        .retry_policy(RetryPolicy::none())
+        .build()
+        .get_latest(&GetLatestNewsParams::default())
+        .unwrap_err();
+    assert!(matches!(error, Error::Transport { .. }));
+}
+
+fn error_response(status: u16, code: &str, message: &str) -> HttpResponse {
+    HttpResponse::new(
+        status,
+        format!(r#"{{"status":"error","results":{{"message":"{message}","code":"{code}"}}}}"#),
+    )
+}
+
+#[test]
+fn error_responses_are_classified_by_status_then_code() {
+    let transport = MockTransport::new();
+    transport.push(
+        error_response(429, "RateLimitExceeded", "Too many requests")
+            .with_header("Retry-After", "7"),
+    );
+    transport.push(error_response(
+        429,
+        "ApiLimitExceeded",
+        "API credits exhausted",
+    ));
+    transport.push(error_response(
+        401,
+        "Unauthorized",
+        "API key is invalid, check your credit card details",
+    ));
+    transport.push(error_response(500, "ServerError", "Out of quota workers"));
+    let newsdata_io = builder(&transport)
+        .retry_policy(RetryPolicy::none())
+        .build();
+    let get_latest = || {
+        newsdata_io
+            .get_latest(&GetLatestNewsParams::default())
+            .unwrap_err()
+    };
+
+    let error = get_latest();
+    assert!(matches!(error, Error::RateLimited { status: 429, .. }));
+    assert_eq!(error.retry_after(), Some(Duration::from_secs(7)));
+    let error = get_latest();
+    assert!(matches!(error, Error::QuotaExceeded { status: 429, .. }));
+    assert_eq!(error.code(), Some("ApiLimitExceeded"));
+    assert!(matches!(
+        get_latest(),
+        Error::Unauthorized { status: 401, .. }
+    ));
+    assert!(matches!(get_latest(), Error::Api { status: 500, .. }));
+}
+
+#[test]
+fn rejected_parameter_is_named_from_the_error_body() {
+    let transport = MockTransport::new();
+    transport.push(error_response(
+        422,
+        "UnsupportedParameter",
+        "The parameter 'catgory' is not supported",
+    ));
+    transport.push(HttpResponse::new(
+        400,
+        r#"{"status":"error","results":{"message":"Invalid value","code":"InvalidValue","field":"language"}}"#,
+    ));
+    transport.push(error_response(400, "InvalidValue", "Invalid value"));
+    let newsdata_io = builder(&transport)
+        .retry_policy(RetryPolicy::none())
+        .build();
+    let field = || match newsdata_io.get_latest(&GetLatestNewsParams::default()) {
+        Err(Error::InvalidParameter { field, .. }) => field,
+        other => panic!("expected an InvalidParameter error, got {other:?}"),
+    };
+
+    assert_eq!(field().as_deref(), Some("catgory"));
+    assert_eq!(field().as_deref(), Some("language"));
+    assert_eq!(field(), None);
+}
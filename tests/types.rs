@@ -0,0 +1,113 @@
+use newsdata_io_api::apis::{
+    Category, Country, GetLatestNewsParams, Language, PriorityDomain, Sentiment,
+};
+use newsdata_io_api::Error;
+
+#[test]
+fn parses_documented_values_case_insensitively() {
+    assert_eq!("us".parse::<Country>().unwrap(), Country::UnitedStates);
+    assert_eq!(
+        " Business ".parse::<Category>().unwrap(),
+        Category::Business
+    );
+    assert_eq!(
+        "other".parse::<Category>().unwrap(),
+        Category::Miscellaneous
+    );
+    assert_eq!(Sentiment::try_from("NEUTRAL").unwrap(), Sentiment::Neutral);
+}
+
+#[test]
+fn strict_parsing_rejects_unknown_values() {
+    match Category::parse_strict("buisness") {
+        Err(Error::InvalidParameter { field, message, .. }) => {
+            assert_eq!(field.as_deref(), Some("category"));
+            assert_eq!(
+                message,
+                "unknown value `buisness`, use `Category::Other` to send an undocumented one"
+            );
+        }
+        other => panic!("expected an invalid parameter error, got {other:?}"),
+    }
+    assert!(Country::try_from("xx".to_string()).is_err());
+}
+
+#[test]
+fn lenient_parsing_keeps_unknown_values() {
+    assert_eq!(
+        "weather".parse::<Category>(),
+        Ok(Category::Other("weather".to_string()))
+    );
+    assert_eq!(
+        serde_json::from_str::<Vec<Category>>(r#"["weather","TOP"]"#).unwrap(),
+        [Category::Other("weather".to_string()), Category::Top]
+    );
+}
+
+/// Serializes every documented value and an undocumented one, and reads them back.
+fn assert_round_trip<T>(all: &[T], other: T)
+where
+    T: Clone + PartialEq + std::fmt::Debug + serde::Serialize + serde::de::DeserializeOwned,
+{
+    let mut values = all.to_vec();
+    values.push(other);
+    let json = serde_json::to_string(&values).unwrap();
+    assert_eq!(serde_json::from_str::<Vec<T>>(&json).unwrap(), values);
+}
+
+#[test]
+fn every_value_survives_a_serde_round_trip() {
+    assert_round_trip(Category::ALL, Category::Other("weather".to_string()));
+    assert_round_trip(Country::ALL, Country::Other("xx".to_string()));
+    assert_round_trip(Language::ALL, Language::Other("klingon".to_string()));
+    assert_round_trip(
+        PriorityDomain::ALL,
+        PriorityDomain::Other("custom".to_string()),
+    );
+    assert_round_trip(Sentiment::ALL, Sentiment::Other("mixed".to_string()));
+}
+
+#[test]
+fn displays_and_serializes_api_codes() {
+    assert_eq!(Country::HongKong.to_string(), "hk");
+    assert_eq!(Category::Miscellaneous.to_string(), "other");
+    assert_eq!(
+        Category::Other("weather".to_string()).to_string(),
+        "weather"
+    );
+    assert_eq!(
+        serde_json::to_string(&[Category::Top, Category::Other("weather".to_string())]).unwrap(),
+        r#"["top","weather"]"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Vec<Country>>(r#"["us","GB"]"#).unwrap(),
+        [Country::UnitedStates, Country::UnitedKingdom]
+    );
+}
+
+#[test]
+fn builder_rejects_unknown_values() {
+    let result = GetLatestNewsParams::builder()
+        .category(["buisness"])
+        .country(["us", "xx", "yy"])
+        .sentiment("happy")
+        .build();
+    match result {
+        Err(Error::InvalidParameter { violations, .. }) => {
+            let fields: Vec<&str> = violations.iter().map(|v| v.field.as_str()).collect();
+            assert_eq!(fields, ["category", "country", "country", "sentiment"]);
+        }
+        other => panic!("expected an invalid parameter error, got {other:?}"),
+    }
+
+    let params = GetLatestNewsParams::builder()
+        .category(["business", "other"])
+        .country(Country::Other("xx".to_string()))
+        .build()
+        .unwrap();
+    assert_eq!(
+        params.category,
+        Some(vec![Category::Business, Category::Miscellaneous])
+    );
+    assert_eq!(params.country, Some(vec![Country::Other("xx".to_string())]));
+}